@@ -0,0 +1,183 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use rust_decimal::prelude::*;
+
+/// Converts an angle from degrees to radians.
+pub fn to_radians(x: Decimal) -> Decimal {
+    x * Decimal::PI / dec!(180)
+}
+
+/// Converts an angle from radians to degrees.
+pub fn to_degrees(x: Decimal) -> Decimal {
+    x * dec!(180) / Decimal::PI
+}
+
+/// Reduces an angle in degrees to the range ``[0, 360)``.
+pub fn normalize_degrees(x: Decimal) -> Decimal {
+    let x = x % dec!(360);
+    if x < Decimal::ZERO {
+        x + dec!(360)
+    } else {
+        x
+    }
+}
+
+/// Returns the sine of an angle given in degrees.
+pub fn sin_deg(x: Decimal) -> Decimal {
+    to_radians(x % dec!(360)).sin()
+}
+
+/// Returns the cosine of an angle given in degrees.
+pub fn cos_deg(x: Decimal) -> Decimal {
+    to_radians(x % dec!(360)).cos()
+}
+
+/// Returns the arcsine of ``x`` in degrees, between -90 and 90.
+/// 
+/// **NOTE:** Inverse functions are evaluated in double precision, which is ample for astronomical positions.
+pub fn asin_deg(x: Decimal) -> Decimal {
+    from_f64(float::asin(x.to_f64().unwrap().clamp(-1.0, 1.0)).to_degrees())
+}
+
+/// Returns the arccosine of ``x`` in degrees, between 0 and 180.
+pub fn acos_deg(x: Decimal) -> Decimal {
+    from_f64(float::acos(x.to_f64().unwrap().clamp(-1.0, 1.0)).to_degrees())
+}
+
+/// Returns the angle of the point ``(x, y)`` in degrees, between -180 and 180.
+pub fn atan2_deg(y: Decimal, x: Decimal) -> Decimal {
+    from_f64(float::atan2(y.to_f64().unwrap(), x.to_f64().unwrap()).to_degrees())
+}
+
+/// The inverse functions of ``f64``, which are provided by ``std`` or, without it, by ``libm``.
+#[cfg(feature = "std")]
+mod float {
+    pub fn asin(x: f64) -> f64 {
+        x.asin()
+    }
+
+    pub fn acos(x: f64) -> f64 {
+        x.acos()
+    }
+
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod float {
+    pub use libm::{acos, asin, atan2};
+}
+
+/// A value split into whole units, minutes and seconds, e.g. an angle in degrees or hours.\
+/// The sign is kept separately, so that e.g. -0°30' does not lose its sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sexagesimal {
+    pub negative: bool,
+    pub whole: u32,
+    pub minutes: u8,
+    pub seconds: Decimal,
+}
+
+/// Converts an angle from degrees to hours, minutes and seconds, at 15 degrees per hour.
+pub fn degrees_to_hms(deg: Decimal) -> Sexagesimal {
+    to_sexagesimal(deg / dec!(15))
+}
+
+/// Converts an angle from degrees to degrees, arcminutes and arcseconds.
+pub fn degrees_to_dms(deg: Decimal) -> Sexagesimal {
+    to_sexagesimal(deg)
+}
+
+/// Converts hours, minutes and seconds into an angle in degrees, the inverse of ``degrees_to_hms``.
+pub fn hms_to_degrees(hms: Sexagesimal) -> Decimal {
+    from_sexagesimal(hms) * dec!(15)
+}
+
+/// Converts degrees, arcminutes and arcseconds into an angle in degrees, the inverse of ``degrees_to_dms``.
+pub fn dms_to_degrees(dms: Sexagesimal) -> Decimal {
+    from_sexagesimal(dms)
+}
+
+/// Splits a value into whole units, minutes and seconds.
+fn to_sexagesimal(x: Decimal) -> Sexagesimal {
+    let abs = x.abs();
+    let whole = abs.trunc();
+    let minutes = ((abs - whole) * dec!(60)).trunc();
+    let seconds = (abs - whole) * dec!(3600) - minutes * dec!(60);
+
+    Sexagesimal {
+        negative: x.is_sign_negative() && !x.is_zero(),
+        whole: whole.to_u32().unwrap(),
+        minutes: minutes.to_u8().unwrap(),
+        seconds,
+    }
+}
+
+/// Joins whole units, minutes and seconds into a single value.
+fn from_sexagesimal(x: Sexagesimal) -> Decimal {
+    let abs = Decimal::from(x.whole) + Decimal::from(x.minutes) / dec!(60) + x.seconds / dec!(3600);
+    if x.negative { -abs } else { abs }
+}
+
+fn from_f64(x: f64) -> Decimal {
+    Decimal::from_f64(x).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::angle::*;
+
+    #[test]
+    fn test_normalize_degrees() {
+        assert_eq!(normalize_degrees(dec!(370)), dec!(10));
+        assert_eq!(normalize_degrees(dec!(-10)), dec!(350));
+        assert_eq!(normalize_degrees(dec!(-720)), dec!(0));
+        assert_eq!(normalize_degrees(dec!(359.5)), dec!(359.5));
+    }
+
+    #[test]
+    fn test_trigonometry() {
+        assert_eq!(sin_deg(dec!(30)).round_dp(10), dec!(0.5));
+        assert_eq!(sin_deg(dec!(-330)).round_dp(10), dec!(0.5));
+        assert_eq!(cos_deg(dec!(60)).round_dp(10), dec!(0.5));
+        assert_eq!(cos_deg(dec!(1000.5)).round_dp(10), cos_deg(dec!(280.5)).round_dp(10));
+        assert_eq!(to_degrees(to_radians(dec!(123.456))).round_dp(10), dec!(123.456));
+    }
+
+    #[test]
+    fn test_sexagesimal() {
+        // 13.161 hours
+        let hms = Sexagesimal {negative: false, whole: 13, minutes: 9, seconds: dec!(39.6)};
+        assert_eq!(degrees_to_hms(dec!(197.415)), hms);
+        assert_eq!(hms_to_degrees(hms), dec!(197.415));
+
+        // Example 13.a, the declination of Venus δ = -6°43'11.61"
+        let dms = degrees_to_dms(dec!(-6.719892));
+        assert_eq!((dms.negative, dms.whole, dms.minutes, dms.seconds.round_dp(2)), (true, 6, 43, dec!(11.61)));
+        let dms = Sexagesimal {negative: true, whole: 6, minutes: 43, seconds: dec!(11.61)};
+        assert_eq!(dms_to_degrees(dms).round_dp(6), dec!(-6.719892));
+
+        assert_eq!(degrees_to_dms(dec!(0.5)), Sexagesimal {negative: false, whole: 0, minutes: 30, seconds: dec!(0)});
+        assert_eq!(degrees_to_hms(dec!(360)), Sexagesimal {negative: false, whole: 24, minutes: 0, seconds: dec!(0)});
+
+        // -0°30' keeps its sign even though the whole degrees are zero
+        let dms = degrees_to_dms(dec!(-0.5));
+        assert_eq!(dms, Sexagesimal {negative: true, whole: 0, minutes: 30, seconds: dec!(0)});
+        assert_eq!(dms_to_degrees(dms), dec!(-0.5));
+
+        let hms = degrees_to_hms(dec!(-7.5));
+        assert_eq!(hms, Sexagesimal {negative: true, whole: 0, minutes: 30, seconds: dec!(0)});
+        assert_eq!(hms_to_degrees(hms), dec!(-7.5));
+    }
+
+    #[test]
+    fn test_inverse_trigonometry() {
+        assert_eq!(asin_deg(dec!(0.5)).round_dp(10), dec!(30));
+        assert_eq!(acos_deg(dec!(0.5)).round_dp(10), dec!(60));
+        assert_eq!(acos_deg(dec!(-1)).round_dp(10), dec!(180));
+        assert_eq!(atan2_deg(dec!(1), dec!(-1)).round_dp(10), dec!(135));
+        assert_eq!(atan2_deg(dec!(-1), dec!(0)).round_dp(10), dec!(-90));
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use core::convert::TryFrom;
+
+use crate::julian::{Calendar, CalendarDate, CalendarDateError, JulianDay, julian_calendar_date};
+
+impl From<NaiveDate> for CalendarDate {
+    /// Converts a proleptic Gregorian NaiveDate into a CalendarDate.
+    /// 
+    /// **NOTE:** Dates before 1582 October 15th are converted into the Julian calendar, e.g. the NaiveDate
+    /// ``1582-10-14`` becomes ``1582-10-04``. Dates before JulianDay 0 (-4712 January 1st, Julian) are supported as well.
+    fn from(date: NaiveDate) -> Self {
+        let cd = CalendarDate::new(date.year(), date.month() as u8, Decimal::from(date.day()));
+        if cd.get_calendar() == Calendar::Gregorian {
+            return cd;
+        }
+
+        let jd = JulianDay::new(Decimal::from(date.num_days_from_ce()) + dec!(1721424.5));
+        julian_calendar_date(jd)
+    }
+}
+
+impl TryFrom<CalendarDate> for NaiveDate {
+    type Error = CalendarDateError;
+
+    /// Converts a CalendarDate into a NaiveDate, discarding the fractional part of the day.
+    /// 
+    /// Fails for dates before 1582 October 15th, which fall under the Julian calendar,
+    /// and for dates outside of the range supported by chrono.
+    fn try_from(cd: CalendarDate) -> Result<Self, Self::Error> {
+        if cd.get_calendar() == Calendar::Julian {
+            return Err(CalendarDateError::UnrepresentableDate);
+        }
+
+        NaiveDate::from_ymd_opt(cd.year(), u32::from(cd.month()), cd.day().trunc().try_into().unwrap_or(0))
+            .ok_or(CalendarDateError::UnrepresentableDate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chrono_compat::*;
+
+    #[test]
+    fn test_from_naive_date() {
+        let date = NaiveDate::from_ymd_opt(1957, 10, 4).unwrap();
+        assert_eq!(CalendarDate::from(date), CalendarDate::new(1957, 10, dec!(4)));
+
+        // Proleptic Gregorian dates before the reform are converted into the Julian calendar
+        let date = NaiveDate::from_ymd_opt(1582, 10, 14).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (1582, 10, dec!(4)));
+
+        let date = NaiveDate::from_ymd_opt(333, 1, 29).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (333, 1, dec!(28)));
+
+        // Dates before JulianDay 0 are shifted by whole cycles of the Julian calendar
+        let date = NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap();
+        assert_eq!(JulianDay::from(CalendarDate::from(date)), JulianDay::new(dec!(-0.5)));
+
+        let date = NaiveDate::from_ymd_opt(-4713, 11, 23).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-4713, 12, dec!(31)));
+
+        let date = NaiveDate::from_ymd_opt(-5000, 1, 1).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-5000, 2, dec!(10)));
+
+        let date = NaiveDate::from_ymd_opt(-262000, 6, 15).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-261995, 11, dec!(3)));
+    }
+
+    #[test]
+    fn test_try_into_naive_date() {
+        let cd = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(NaiveDate::try_from(cd).unwrap(), NaiveDate::from_ymd_opt(1957, 10, 4).unwrap());
+
+        let cd = CalendarDate::new(1582, 10, dec!(15));
+        assert_eq!(NaiveDate::try_from(cd).unwrap(), NaiveDate::from_ymd_opt(1582, 10, 15).unwrap());
+
+        assert!(NaiveDate::try_from(CalendarDate::new(1582, 10, dec!(4))).is_err());
+        assert!(NaiveDate::try_from(CalendarDate::new(2000, 2, dec!(30))).is_err());
+        assert!(NaiveDate::try_from(CalendarDate::new(i32::MAX, 1, dec!(1))).is_err());
+    }
+}
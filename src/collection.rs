@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use crate::julian::CalendarDate;
+
+/// A set of CalendarDate objects, kept sorted by JulianDay.
+/// 
+/// **NOTE:** Dates are compared by the instant they correspond to,
+/// so the same day expressed in two different calendars is only stored once.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalendarCollection {
+    dates: Vec<CalendarDate>,
+}
+
+impl CalendarCollection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a date, keeping the collection sorted.\
+    /// Returns false if the collection already contained the date.
+    pub fn insert(&mut self, date: CalendarDate) -> bool {
+        match self.dates.binary_search(&date) {
+            Ok(_) => false,
+            Err(i) => {
+                self.dates.insert(i, date);
+                true
+            }
+        }
+    }
+
+    /// Determines if the collection contains the given date.
+    pub fn contains(&self, date: &CalendarDate) -> bool {
+        self.dates.binary_search(date).is_ok()
+    }
+
+    /// Returns the date closest to ``target``, or None if the collection is empty.\
+    /// When two dates are equally close, the earlier one is returned.
+    pub fn nearest(&self, target: &CalendarDate) -> Option<&CalendarDate> {
+        let i = match self.dates.binary_search(target) {
+            Ok(i) => return self.dates.get(i),
+            Err(i) => i,
+        };
+
+        let before = i.checked_sub(1).and_then(|i| self.dates.get(i));
+        let after = self.dates.get(i);
+
+        match (before, after) {
+            (Some(b), Some(a)) => {
+                if CalendarDate::days_between(a, target) < CalendarDate::days_between(b, target) {
+                    Some(a)
+                } else {
+                    Some(b)
+                }
+            },
+            (b, a) => b.or(a),
+        }
+    }
+
+    /// Returns the dates from ``start`` (inclusive) to ``end`` (exclusive), in order.
+    pub fn range(&self, start: &CalendarDate, end: &CalendarDate) -> &[CalendarDate] {
+        let lower = self.dates.partition_point(|d| d < start);
+        let upper = self.dates.partition_point(|d| d < end).max(lower);
+
+        &self.dates[lower..upper]
+    }
+
+    /// Returns the earliest date, or None if the collection is empty.
+    pub fn first(&self) -> Option<&CalendarDate> {
+        self.dates.first()
+    }
+
+    /// Returns the latest date, or None if the collection is empty.
+    pub fn last(&self) -> Option<&CalendarDate> {
+        self.dates.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    /// Iterates over the dates in chronological order.
+    pub fn iter(&self) -> core::slice::Iter<'_, CalendarDate> {
+        self.dates.iter()
+    }
+}
+
+impl FromIterator<CalendarDate> for CalendarCollection {
+    fn from_iter<I: IntoIterator<Item = CalendarDate>>(iter: I) -> Self {
+        let mut dates: Vec<CalendarDate> = iter.into_iter().collect();
+        dates.sort();
+        dates.dedup();
+
+        Self { dates }
+    }
+}
+
+impl Extend<CalendarDate> for CalendarCollection {
+    fn extend<I: IntoIterator<Item = CalendarDate>>(&mut self, iter: I) {
+        for date in iter {
+            self.insert(date);
+        }
+    }
+}
+
+impl IntoIterator for CalendarCollection {
+    type Item = CalendarDate;
+    type IntoIter = alloc::vec::IntoIter<CalendarDate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.dates.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CalendarCollection {
+    type Item = &'a CalendarDate;
+    type IntoIter = core::slice::Iter<'a, CalendarDate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.dates.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::*;
+    use crate::julian::{Calendar, ParseError};
+    use rust_decimal::dec;
+
+    fn sample() -> CalendarCollection {
+        ["2000-03-01", "1999-12-31", "2000-01-15", "2000-01-01"]
+            .iter()
+            .map(|s| CalendarDate::parse_iso8601(s))
+            .collect::<Result<CalendarCollection, ParseError>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_collect_sorted() {
+        let collection = sample();
+        let days: Vec<String> = collection.iter().map(|d| d.to_string()).collect();
+        assert_eq!(days, ["1999-12-31", "2000-01-01", "2000-01-15", "2000-03-01"]);
+
+        // Duplicates are dropped, including the same instant expressed in another calendar
+        let collection: CalendarCollection = [
+            CalendarDate::new(1582, 10, dec!(4)),
+            CalendarDate::new(1582, 10, dec!(4)),
+            CalendarDate::new(1582, 10, dec!(4)).to_calendar(Calendar::Gregorian),
+        ].into_iter().collect();
+        assert_eq!(collection.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_contains() {
+        let mut collection = sample();
+        assert!(collection.contains(&CalendarDate::new(2000, 1, dec!(15))));
+        assert!(!collection.contains(&CalendarDate::new(2000, 1, dec!(16))));
+
+        assert!(collection.insert(CalendarDate::new(2000, 1, dec!(16))));
+        assert!(!collection.insert(CalendarDate::new(2000, 1, dec!(16))));
+        assert!(collection.contains(&CalendarDate::new(2000, 1, dec!(16))));
+        assert_eq!(collection.len(), 5);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let collection = sample();
+        assert_eq!(collection.nearest(&CalendarDate::new(1900, 1, dec!(1))), Some(&CalendarDate::new(1999, 12, dec!(31))));
+        assert_eq!(collection.nearest(&CalendarDate::new(2000, 1, dec!(10))), Some(&CalendarDate::new(2000, 1, dec!(15))));
+        // Equally close to 2000-01-01 and 2000-01-15
+        assert_eq!(collection.nearest(&CalendarDate::new(2000, 1, dec!(8))), Some(&CalendarDate::new(2000, 1, dec!(1))));
+        assert_eq!(collection.nearest(&CalendarDate::new(2000, 1, dec!(15))), Some(&CalendarDate::new(2000, 1, dec!(15))));
+        assert_eq!(collection.nearest(&CalendarDate::new(2100, 1, dec!(1))), Some(&CalendarDate::new(2000, 3, dec!(1))));
+        assert_eq!(CalendarCollection::new().nearest(&CalendarDate::new(2000, 1, dec!(1))), None);
+    }
+
+    #[test]
+    fn test_range() {
+        let collection = sample();
+        let range = collection.range(&CalendarDate::new(2000, 1, dec!(1)), &CalendarDate::new(2000, 3, dec!(1)));
+        assert_eq!(range, [CalendarDate::new(2000, 1, dec!(1)), CalendarDate::new(2000, 1, dec!(15))]);
+
+        assert!(collection.range(&CalendarDate::new(2001, 1, dec!(1)), &CalendarDate::new(2002, 1, dec!(1))).is_empty());
+        assert!(collection.range(&CalendarDate::new(2000, 3, dec!(1)), &CalendarDate::new(1999, 1, dec!(1))).is_empty());
+    }
+}
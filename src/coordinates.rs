@@ -0,0 +1,60 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{asin_deg, atan2_deg, cos_deg, normalize_degrees, sin_deg};
+use crate::julian::JulianDay;
+use crate::sidereal::greenwich_mean_sidereal_time;
+
+/// Returns the local hour angle in degrees between 0 and 360 of an object at right ascension ``ra``,
+/// for an observer at ``longitude`` (positive east), both in degrees.\
+/// Defined as: ``H = θ0 + L - α``, where ``θ0`` is the mean sidereal time at Greenwich.
+/// 
+/// **NOTE:** ``jd`` must be expressed in Universal Time (UT), not Dynamical Time.
+pub fn local_hour_angle(jd: JulianDay, longitude: Decimal, ra: Decimal) -> Decimal {
+    normalize_degrees(greenwich_mean_sidereal_time(jd) + longitude - ra)
+}
+
+/// Converts the local hour angle ``ha`` and declination ``dec`` of an object into its ``(azimuth, altitude)``,
+/// for an observer at ``latitude`` (positive north), all in degrees.\
+/// The azimuth lies between 0 and 360, measured from the North towards the East. The altitude lies between -90 and 90.
+/// 
+/// Taken from "Transformation of Coordinates"
+/// 
+/// **NOTE:** The book measures the azimuth from the South, which is 180 degrees away from the azimuth returned here.\
+/// The altitude is geometric, i.e. not corrected for atmospheric refraction.
+pub fn equatorial_to_horizontal(ha: Decimal, dec: Decimal, latitude: Decimal) -> (Decimal, Decimal) {
+    let azimuth = atan2_deg(
+        sin_deg(ha) * cos_deg(dec),
+        cos_deg(ha) * sin_deg(latitude) * cos_deg(dec) - sin_deg(dec) * cos_deg(latitude),
+    );
+    let altitude = asin_deg(sin_deg(latitude) * sin_deg(dec) + cos_deg(latitude) * cos_deg(dec) * cos_deg(ha));
+
+    (normalize_degrees(azimuth + dec!(180)), altitude)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coordinates::*;
+
+    #[test]
+    fn test_equatorial_to_horizontal() {
+        // Example 13.b, Venus seen from the U.S. Naval Observatory on 1987 April 10 at 19h21m00s UT
+        let (azimuth, altitude) = equatorial_to_horizontal(dec!(64.352133), dec!(-6.719892), dec!(38.921389));
+        assert!((azimuth - dec!(248.0337)).abs() < dec!(0.0001));
+        assert!((altitude - dec!(15.1249)).abs() < dec!(0.0001));
+
+        // An object on the meridian, south of the zenith, and the celestial pole
+        let (azimuth, altitude) = equatorial_to_horizontal(Decimal::ZERO, dec!(10), dec!(40));
+        assert_eq!((azimuth.round_dp(6), altitude.round_dp(6)), (dec!(180), dec!(60)));
+        let (_, altitude) = equatorial_to_horizontal(dec!(123), dec!(90), dec!(40));
+        assert_eq!(altitude.round_dp(6), dec!(40));
+    }
+
+    #[test]
+    fn test_local_hour_angle() {
+        // Example 13.b, which uses the apparent rather than the mean sidereal time
+        let jd = JulianDay::new(dec!(2446896.30625));
+        let ha = local_hour_angle(jd, dec!(-77.065556), dec!(347.319338));
+        assert!((ha - dec!(64.352133)).abs() < dec!(0.001));
+    }
+}
@@ -0,0 +1,168 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal::dec;
+use rust_decimal::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::julian::{CalendarDate, CalendarDateError, JulianDay};
+
+/// A calendar date with an explicit time of day, rather than a fractional day.
+/// 
+/// ``date`` always carries an integer day, the time of day counts from midnight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateTime {
+    pub date: CalendarDate,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: Decimal,
+}
+
+/// How ``DateTime::rounded`` rounds fractional seconds to whole seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SecondsRounding {
+    /// Drops the fractional seconds, as ``Display`` does.
+    Truncate,
+    /// Rounds to the nearest second, with half a second rounded up.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest second, with half a second rounded to the even second (bankers' rounding).
+    HalfEven,
+}
+
+impl DateTime {
+    /// Does not validate input. The date and time MUST be valid.
+    pub fn new(y: i32, m: u8, d: u8, hour: u8, minute: u8, second: Decimal) -> Self {
+        Self {
+            date: CalendarDate::new(y, m, Decimal::from(d)),
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Returns this DateTime with its seconds rounded to a whole number according to ``rounding``.\
+    /// Rounding up to 60 seconds carries into the minutes, hours and date, e.g. 23:59:59.6 rounds to 00:00:00 on the next day.
+    /// 
+    /// **NOTE:** The next day is found through the JulianDay, so 1582 October 4th rolls over to October 15th.
+    pub fn rounded(self, rounding: SecondsRounding) -> DateTime {
+        let seconds = Decimal::from(self.hour) * dec!(3600) + Decimal::from(self.minute) * dec!(60) + self.second;
+        let seconds = match rounding {
+            SecondsRounding::Truncate => seconds.trunc(),
+            SecondsRounding::HalfUp => seconds.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero),
+            SecondsRounding::HalfEven => seconds.round_dp_with_strategy(0, RoundingStrategy::MidpointNearestEven),
+        };
+
+        let (date, seconds) = if seconds >= dec!(86400) {
+            (self.date.succ(), seconds - dec!(86400))
+        } else {
+            (self.date, seconds)
+        };
+
+        let seconds = seconds.to_u32().unwrap();
+        Self {
+            date,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds % 3600 / 60) as u8,
+            second: Decimal::from(seconds % 60),
+        }
+    }
+}
+
+impl From<CalendarDate> for DateTime {
+    /// Splits the fractional day of a CalendarDate into an explicit time of day.
+    fn from(cd: CalendarDate) -> Self {
+        let (hour, minute, second) = cd.time_of_day();
+
+        Self {
+            date: CalendarDate::new(cd.year(), cd.month(), cd.day().trunc()),
+            hour,
+            minute,
+            second,
+        }
+    }
+}
+
+impl From<DateTime> for CalendarDate {
+    /// Folds the time of day back into the fractional day.
+    fn from(dt: DateTime) -> Self {
+        let seconds = Decimal::from(dt.hour) * dec!(3600) + Decimal::from(dt.minute) * dec!(60) + dt.second;
+        CalendarDate::new(dt.date.year(), dt.date.month(), dt.date.day() + seconds / dec!(86400))
+    }
+}
+
+impl From<DateTime> for JulianDay {
+    fn from(dt: DateTime) -> Self {
+        JulianDay::from(CalendarDate::from(dt))
+    }
+}
+
+impl TryFrom<JulianDay> for DateTime {
+    type Error = CalendarDateError;
+
+    /// **NOTE:** We require jd >= 0 for the conversion to be successful, refer to ``CalendarDate::try_from``.
+    fn try_from(jd: JulianDay) -> Result<Self, Self::Error> {
+        CalendarDate::try_from(jd).map(DateTime::from)
+    }
+}
+
+impl fmt::Display for DateTime {
+    /// Renders this DateTime as ``YYYY-MM-DDThh:mm:ss``, truncating fractional seconds.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{:02}:{:02}:{:02}", self.date, self.hour, self.minute, self.second.trunc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::*;
+
+    #[test]
+    fn test_from_calendar_date() {
+        let dt = DateTime::from(CalendarDate::new(1957, 10, dec!(4.81)));
+        assert_eq!(dt, DateTime::new(1957, 10, 4, 19, 26, dec!(24)));
+        assert_eq!(CalendarDate::from(dt), CalendarDate::new(1957, 10, dec!(4.81)));
+    }
+
+    #[test]
+    fn test_julian_day() {
+        let dt = DateTime::new(2000, 1, 1, 12, 0, dec!(0));
+        assert_eq!(JulianDay::from(dt), JulianDay::new(dec!(2451545.0)));
+        assert_eq!(DateTime::try_from(JulianDay::new(dec!(2451545.0))).unwrap(), dt);
+
+        assert!(DateTime::try_from(JulianDay::new(dec!(-1))).is_err());
+    }
+
+    #[test]
+    fn test_rounded() {
+        let dt = DateTime::new(1957, 10, 4, 19, 26, dec!(24.5));
+        assert_eq!(dt.rounded(SecondsRounding::Truncate), DateTime::new(1957, 10, 4, 19, 26, dec!(24)));
+        assert_eq!(dt.rounded(SecondsRounding::HalfUp), DateTime::new(1957, 10, 4, 19, 26, dec!(25)));
+        assert_eq!(dt.rounded(SecondsRounding::HalfEven), DateTime::new(1957, 10, 4, 19, 26, dec!(24)));
+        assert_eq!(DateTime::new(1957, 10, 4, 19, 26, dec!(25.5)).rounded(SecondsRounding::HalfEven), DateTime::new(1957, 10, 4, 19, 26, dec!(26)));
+        assert_eq!(SecondsRounding::default(), SecondsRounding::HalfUp);
+
+        // Carries into the minutes and hours
+        let dt = DateTime::new(2000, 1, 1, 9, 59, dec!(59.7));
+        assert_eq!(dt.rounded(SecondsRounding::default()), DateTime::new(2000, 1, 1, 10, 0, dec!(0)));
+
+        // 0.999994 of a day is 23h59m59.48s, just short of rolling over
+        let dt = DateTime::from(CalendarDate::new(1999, 12, dec!(31.999994)));
+        assert_eq!(dt.rounded(SecondsRounding::HalfUp), DateTime::new(1999, 12, 31, 23, 59, dec!(59)));
+
+        // 0.999996 of a day is 23h59m59.65s, which rolls over into the next year
+        let dt = DateTime::from(CalendarDate::new(1999, 12, dec!(31.999996)));
+        assert_eq!(dt.rounded(SecondsRounding::Truncate), DateTime::new(1999, 12, 31, 23, 59, dec!(59)));
+        assert_eq!(dt.rounded(SecondsRounding::HalfUp), DateTime::new(2000, 1, 1, 0, 0, dec!(0)));
+        assert_eq!(dt.rounded(SecondsRounding::HalfEven).to_string(), "2000-01-01T00:00:00");
+
+        // Rolling over bridges the Gregorian reform
+        let dt = DateTime::new(1582, 10, 4, 23, 59, dec!(59.5));
+        assert_eq!(dt.rounded(SecondsRounding::HalfUp).to_string(), "1582-10-15T00:00:00");
+    }
+
+    #[test]
+    fn test_display() {
+        let dt = DateTime::new(1957, 10, 4, 19, 26, dec!(24.5));
+        assert_eq!(dt.to_string(), "1957-10-04T19:26:24");
+    }
+}
@@ -0,0 +1,178 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use rust_decimal::prelude::*;
+
+use crate::julian::JulianDay;
+
+/// The constant offset ``TD - TAI``, in seconds.
+const TD_MINUS_TAI: Decimal = dec!(32.184);
+
+/// A time scale a JulianDay can be expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TimeScale {
+    /// Universal Time, following the rotation of the Earth.
+    UT,
+    /// Dynamical Time (also called Terrestrial Time, TT), the uniform time scale of the ephemerides.
+    TD,
+    /// International Atomic Time.
+    TAI,
+}
+
+/// A JulianDay tagged with the time scale it is expressed in, created by ``JulianDay::in_scale``.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaledJulianDay {
+    pub jd: JulianDay,
+    pub scale: TimeScale,
+}
+
+impl ScaledJulianDay {
+    /// Returns the same instant expressed in another time scale.
+    /// 
+    /// - UT and TD differ by ΔT, estimated for the year of the instant, refer to ``delta_t``
+    /// - TD and TAI differ by exactly 32.184 seconds
+    pub fn convert_scale(self, scale: TimeScale) -> ScaledJulianDay {
+        let td = match self.scale {
+            TimeScale::UT => self.jd + delta_t(approximate_year(self.jd)) / dec!(86400),
+            TimeScale::TD => self.jd,
+            TimeScale::TAI => self.jd + TD_MINUS_TAI / dec!(86400),
+        };
+
+        let jd = match scale {
+            TimeScale::UT => td - delta_t(approximate_year(td)) / dec!(86400),
+            TimeScale::TD => td,
+            TimeScale::TAI => td - TD_MINUS_TAI / dec!(86400),
+        };
+
+        ScaledJulianDay { jd, scale }
+    }
+}
+
+/// Returns the year an instant falls in, close enough to estimate ΔT.
+fn approximate_year(jd: JulianDay) -> i32 {
+    (dec!(2000) + (jd - JulianDay::J2000) / dec!(365.25)).floor().to_i32().unwrap()
+}
+
+/// Returns an estimate of ``ΔT = TD - UT`` in seconds for the given year.
+/// 
+/// Taken from "Dynamical Time and Universal Time"
+/// - Before 948, and from 948 to 1600, the parabolic fits of Meeus are used
+/// - From 1600 to 2050, the polynomial fits of Espenak and Meeus are used
+/// - After 2050, the long-term parabola is used, blended into the 2050 value until 2150
+/// 
+/// **NOTE:** ΔT can only be deduced from observations. Values for the far past and for years
+/// after the present are extrapolations, and may be off by minutes (or hours, in antiquity).
+pub fn delta_t(year: i32) -> Decimal {
+    let y = Decimal::from(year);
+
+    match year {
+        ..948 => {
+            let t = (y - dec!(2000)) / dec!(100);
+            dec!(2177) + dec!(497) * t + dec!(44.1) * t * t
+        },
+        948..1600 => {
+            let t = (y - dec!(2000)) / dec!(100);
+            dec!(102) + dec!(102) * t + dec!(25.3) * t * t
+        },
+        1600..1700 => {
+            let t = y - dec!(1600);
+            polynomial(t, &[dec!(120), dec!(-0.9808), dec!(-0.01532)]) + t * t * t / dec!(7129)
+        },
+        1700..1800 => {
+            let t = y - dec!(1700);
+            polynomial(t, &[dec!(8.83), dec!(0.1603), dec!(-0.0059285), dec!(0.00013336)]) - t * t * t * t / dec!(1174000)
+        },
+        1800..1860 => {
+            let t = y - dec!(1800);
+            polynomial(t, &[
+                dec!(13.72), dec!(-0.332447), dec!(0.0068612), dec!(0.0041116),
+                dec!(-0.00037436), dec!(0.0000121272), dec!(-0.0000001699), dec!(0.000000000875),
+            ])
+        },
+        1860..1900 => {
+            let t = y - dec!(1860);
+            polynomial(t, &[dec!(7.62), dec!(0.5737), dec!(-0.251754), dec!(0.01680668), dec!(-0.0004473624)])
+                + t * t * t * t * t / dec!(233174)
+        },
+        1900..1920 => {
+            let t = y - dec!(1900);
+            polynomial(t, &[dec!(-2.79), dec!(1.494119), dec!(-0.0598939), dec!(0.0061966), dec!(-0.000197)])
+        },
+        1920..1941 => {
+            let t = y - dec!(1920);
+            polynomial(t, &[dec!(21.20), dec!(0.84493), dec!(-0.076100), dec!(0.0020936)])
+        },
+        1941..1961 => {
+            let t = y - dec!(1950);
+            dec!(29.07) + dec!(0.407) * t - t * t / dec!(233) + t * t * t / dec!(2547)
+        },
+        1961..1986 => {
+            let t = y - dec!(1975);
+            dec!(45.45) + dec!(1.067) * t - t * t / dec!(260) - t * t * t / dec!(718)
+        },
+        1986..2005 => {
+            let t = y - dec!(2000);
+            polynomial(t, &[dec!(63.86), dec!(0.3345), dec!(-0.060374), dec!(0.0017275), dec!(0.000651814), dec!(0.00002373599)])
+        },
+        2005..2050 => {
+            let t = y - dec!(2000);
+            polynomial(t, &[dec!(62.92), dec!(0.32217), dec!(0.005589)])
+        },
+        2050..2150 => {
+            let u = (y - dec!(1820)) / dec!(100);
+            dec!(-20) + dec!(32) * u * u - dec!(0.5628) * (dec!(2150) - y)
+        },
+        _ => {
+            let u = (y - dec!(1820)) / dec!(100);
+            dec!(-20) + dec!(32) * u * u
+        },
+    }
+}
+
+/// Evaluates ``c[0] + c[1] t + c[2] t² + ...`` using Horner's method.
+fn polynomial(t: Decimal, coefficients: &[Decimal]) -> Decimal {
+    coefficients.iter().rev().fold(Decimal::ZERO, |acc, &c| acc * t + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamical::*;
+
+    #[test]
+    fn test_delta_t() {
+        // Example 10.a, 1977 February 18
+        assert_eq!(delta_t(1977).round(), dec!(48));
+
+        // Example 10.b, 333 February 6
+        assert_eq!(delta_t(333).round(), dec!(6147));
+
+        assert_eq!(delta_t(1900).round(), dec!(-3));
+        assert_eq!(delta_t(2000).round(), dec!(64));
+        assert_eq!(delta_t(2010).round(), dec!(67));
+    }
+
+    #[test]
+    fn test_convert_scale() {
+        // Example 10.a, 1977 February 18 at 3h37m40s TD, with ΔT = 48s
+        let td = JulianDay::new(dec!(2443192.65118)).in_scale(TimeScale::TD);
+        let ut = td.convert_scale(TimeScale::UT);
+        assert_eq!(ut.scale, TimeScale::UT);
+        assert_eq!(((td.jd - ut.jd) * dec!(86400)).round(), dec!(48));
+        assert_eq!(ut.convert_scale(TimeScale::TD), td);
+        assert_eq!(ut.jd.to_dynamical_time(1977), td.jd);
+
+        let tai = td.convert_scale(TimeScale::TAI);
+        assert_eq!((td.jd - tai.jd) * dec!(86400), dec!(32.184));
+        assert_eq!(tai.convert_scale(TimeScale::UT), ut);
+        assert_eq!(td.convert_scale(TimeScale::TD), td);
+    }
+
+    #[test]
+    fn test_delta_t_continuity() {
+        // Neighbouring years should never jump by more than a few seconds from 1600 onwards
+        for year in 1600..2200 {
+            let jump = (delta_t(year + 1) - delta_t(year)).abs();
+            assert!(jump < dec!(5), "ΔT jumps by {} between {} and {}", jump, year, year + 1);
+        }
+    }
+}
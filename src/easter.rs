@@ -0,0 +1,134 @@
+use rust_decimal::Decimal;
+
+use crate::julian::CalendarDate;
+
+/// Returns the date of Easter Sunday for the given year.
+/// 
+/// Taken from "Date of Easter"
+/// - If ``year`` is 1583 or later, the Gregorian method is used
+/// - If ``year`` is before 1583, the Julian method is used
+pub fn easter(year: i32) -> CalendarDate {
+    let (month, day) = if year >= 1583 {
+        gregorian_easter(year)
+    } else {
+        julian_easter(year)
+    };
+
+    CalendarDate::new(year, month, Decimal::from(day))
+}
+
+/// Returns the Dominical letter for the given year, the letter (``A`` to ``G``) given to the Sundays of the year.
+/// 
+/// **NOTE:** A leap year has two Dominical letters, one until February 29 and one from March 1.
+/// The second one is returned, since it is the one that applies to Easter.
+pub fn dominical_letter(year: i32) -> char {
+    let weekday = CalendarDate::from_ymd(year, 3, 1).day_of_the_week() as u8;
+
+    // March 1 carries the letter D, whether or not the year is a leap year
+    (b'A' + (3 + (7 - weekday) % 7) % 7) as char
+}
+
+/// Returns the epact for the given year, the age of the Moon on January 1, between 0 and 29.
+/// - If ``year`` is 1583 or later, the Gregorian epact is used
+/// - If ``year`` is before 1583, the Julian epact is used
+/// 
+/// **NOTE:** The epact is returned as computed, without the adjustments of epacts 24 and 25 used for Easter.\
+/// The Julian epacts follow the same convention as the Gregorian ones, so golden number 1 gives the epact 8.
+pub fn epact(year: i32) -> u8 {
+    let golden_number = year.rem_euclid(19) + 1;
+
+    let epact = if year >= 1583 {
+        let c = year / 100 + 1;
+        let x = 3 * c / 4 - 12;
+        let z = (8 * c + 5) / 25 - 5;
+        (11 * golden_number + 20 + z - x).rem_euclid(30)
+    } else {
+        (11 * (golden_number - 1) + 8) % 30
+    };
+
+    epact as u8
+}
+
+/// Method valid for all years of the Gregorian calendar.
+fn gregorian_easter(year: i32) -> (u8, u8) {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = (h + l - 7 * m + 114) / 31;
+    let p = (h + l - 7 * m + 114) % 31;
+
+    (n as u8, (p + 1) as u8)
+}
+
+/// Method valid for all years of the Julian calendar, including negative (astronomical) years.
+fn julian_easter(year: i32) -> (u8, u8) {
+    let a = year.rem_euclid(4);
+    let b = year.rem_euclid(7);
+    let c = year.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let f = (d + e + 114) / 31;
+    let g = (d + e + 114) % 31;
+
+    (f as u8, (g + 1) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use crate::easter::*;
+
+    #[test]
+    fn test_gregorian_easter() {
+        assert_eq!(easter(1991), CalendarDate::new(1991, 3, dec!(31)));
+        assert_eq!(easter(1992), CalendarDate::new(1992, 4, dec!(19)));
+        assert_eq!(easter(1993), CalendarDate::new(1993, 4, dec!(11)));
+        assert_eq!(easter(1954), CalendarDate::new(1954, 4, dec!(18)));
+        assert_eq!(easter(2000), CalendarDate::new(2000, 4, dec!(23)));
+        assert_eq!(easter(1818), CalendarDate::new(1818, 3, dec!(22)));
+    }
+
+    #[test]
+    fn test_julian_easter() {
+        assert_eq!(easter(179), CalendarDate::new(179, 4, dec!(12)));
+        assert_eq!(easter(711), CalendarDate::new(711, 4, dec!(12)));
+        assert_eq!(easter(1243), CalendarDate::new(1243, 4, dec!(12)));
+    }
+
+    #[test]
+    fn test_dominical_letter() {
+        assert_eq!(dominical_letter(1991), 'F');
+        assert_eq!(dominical_letter(2000), 'A');
+        assert_eq!(dominical_letter(2023), 'A');
+        assert_eq!(dominical_letter(2024), 'F');
+        assert_eq!(dominical_letter(2025), 'E');
+        assert_eq!(dominical_letter(2100), 'C');
+        assert_eq!(dominical_letter(1500), 'D');
+        assert_eq!(dominical_letter(1), 'B');
+    }
+
+    #[test]
+    fn test_epact() {
+        assert_eq!(epact(2000), 24);
+        assert_eq!(epact(2001), 5);
+        assert_eq!(epact(2024), 19);
+        assert_eq!(epact(1900), 29);
+        assert_eq!(epact(1583), 7);
+        assert_eq!(epact(1577), 8);
+        assert_eq!(epact(1582), 3);
+
+        // The solar equation outweighs the lunar one in late centuries, so the sum goes negative before reducing
+        assert_eq!(epact(9006), 29);
+        assert!((1583..10000).all(|year| epact(year) < 30));
+    }
+}
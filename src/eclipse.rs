@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{cos_deg, sin_deg};
+use crate::julian::{CalendarDate, JulianDay};
+use crate::moon::{PhaseArguments, mean_lunation, moon_phase, phase_arguments};
+
+/// The kind of an eclipse found by ``eclipses_in_year``.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseKind {
+    /// The Moon passes in front of the Sun, at New Moon.
+    Solar,
+    /// The Moon passes through the shadow of the Earth, at Full Moon. Penumbral eclipses are included.
+    Lunar,
+}
+
+/// Returns every eclipse of the given year in chronological order, along with the instant of the New Moon or Full Moon at which it occurs.
+/// 
+/// Taken from "Eclipses", which only tells whether an eclipse occurs, not where it is visible.\
+/// The instant of greatest eclipse differs from that of the New Moon or Full Moon by up to a quarter of an hour.
+/// 
+/// **NOTE:** The instants are expressed in Dynamical Time (TD), and the year is taken in Dynamical Time as well.
+pub fn eclipses_in_year(year: i32) -> Vec<(EclipseKind, JulianDay)> {
+    let start = JulianDay::from(CalendarDate::from_ymd(year, 1, 1));
+    let end = JulianDay::from(CalendarDate::from_ymd(year + 1, 1, 1));
+
+    let mut k = mean_lunation(start) - Decimal::ONE;
+    let last = mean_lunation(end) + Decimal::ONE;
+    let mut eclipses = Vec::new();
+
+    while k <= last {
+        for (kind, k) in [(EclipseKind::Solar, k), (EclipseKind::Lunar, k + dec!(0.5))] {
+            if !is_eclipse(kind, k) {
+                continue;
+            }
+
+            let jde = moon_phase(k);
+            if jde.day >= start.day && jde.day < end.day {
+                eclipses.push((kind, jde));
+            }
+        }
+        k += Decimal::ONE;
+    }
+
+    eclipses
+}
+
+/// Determines if there is an eclipse at the New Moon (solar) or Full Moon (lunar) ``k``.\
+/// ``k`` counts lunations as in ``moon_phase``, and must be an integer for a solar eclipse or an integer plus 0.5 for a lunar eclipse.
+fn is_eclipse(kind: EclipseKind, k: Decimal) -> bool {
+    let PhaseArguments { e, m, mp, f, omega } = phase_arguments(k);
+
+    // The Moon is too far from a node of its orbit
+    if sin_deg(f).abs() > dec!(0.36) {
+        return false;
+    }
+
+    let f1 = f - dec!(0.02665) * sin_deg(omega);
+
+    let p = dec!(0.2070) * e * sin_deg(m)
+        + dec!(0.0024) * e * sin_deg(dec!(2) * m)
+        - dec!(0.0392) * sin_deg(mp)
+        + dec!(0.0116) * sin_deg(dec!(2) * mp)
+        - dec!(0.0073) * e * sin_deg(mp + m)
+        + dec!(0.0067) * e * sin_deg(mp - m)
+        + dec!(0.0118) * sin_deg(dec!(2) * f1);
+    let q = dec!(5.2207)
+        - dec!(0.0048) * e * cos_deg(m)
+        + dec!(0.0020) * e * cos_deg(dec!(2) * m)
+        - dec!(0.3299) * cos_deg(mp)
+        - dec!(0.0060) * e * cos_deg(mp + m)
+        + dec!(0.0041) * e * cos_deg(mp - m);
+
+    // The least distance from the axis of the shadow to the center of the Earth (solar) or the Moon (lunar), in Earth radii
+    let w = cos_deg(f1).abs();
+    let gamma = (p * cos_deg(f1) + q * sin_deg(f1)) * (Decimal::ONE - dec!(0.0048) * w);
+    let u = dec!(0.0059)
+        + dec!(0.0046) * e * cos_deg(m)
+        - dec!(0.0182) * cos_deg(mp)
+        + dec!(0.0004) * cos_deg(dec!(2) * mp)
+        - dec!(0.0005) * cos_deg(m + mp);
+
+    match kind {
+        EclipseKind::Solar => gamma.abs() <= dec!(1.5433) + u,
+        // The penumbral magnitude ``(1.5573 + u - |γ|) / 0.5450`` must be positive
+        EclipseKind::Lunar => gamma.abs() <= dec!(1.5573) + u,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eclipse::*;
+
+    #[test]
+    fn test_is_eclipse() {
+        // Example 54.a, the solar eclipse of 1993 May 21, greatest at JDE 2449129.0979 a few minutes after the New Moon
+        assert!(is_eclipse(EclipseKind::Solar, dec!(-82)));
+        assert!((moon_phase(dec!(-82)).day - dec!(2449129.0979)).abs() < dec!(0.01));
+
+        // Example 54.b, the penumbral lunar eclipse of 1973 June 15
+        assert!(is_eclipse(EclipseKind::Lunar, dec!(-328.5)));
+
+        // Followed by the long total solar eclipse of 1973 June 30
+        assert!(is_eclipse(EclipseKind::Solar, dec!(-328)));
+
+        // The New Moon of 1977 February 18th (Example 49.a) was too far from a node
+        assert!(!is_eclipse(EclipseKind::Solar, dec!(-283)));
+    }
+
+    #[test]
+    fn test_eclipses_in_year() {
+        // 2024: penumbral lunar on March 25th, total solar on April 8th, partial lunar on September 18th, annular solar on October 2nd
+        let eclipses = eclipses_in_year(2024);
+        let kinds: Vec<EclipseKind> = eclipses.iter().map(|&(kind, _)| kind).collect();
+        assert_eq!(kinds, vec![EclipseKind::Lunar, EclipseKind::Solar, EclipseKind::Lunar, EclipseKind::Solar]);
+
+        let total = CalendarDate::try_from(eclipses[1].1).unwrap();
+        let (h, _, _) = total.time_of_day();
+        assert_eq!((total.to_string(), h), (String::from("2024-04-08"), 18));
+
+        // 2020 had four penumbral lunar eclipses, and 2011 four partial solar eclipses
+        let eclipses = eclipses_in_year(2020);
+        assert_eq!(eclipses.len(), 6);
+        assert_eq!(eclipses.iter().filter(|&&(kind, _)| kind == EclipseKind::Lunar).count(), 4);
+
+        let eclipses = eclipses_in_year(2011);
+        assert_eq!(eclipses.len(), 6);
+        assert_eq!(eclipses.iter().filter(|&&(kind, _)| kind == EclipseKind::Solar).count(), 4);
+    }
+}
@@ -0,0 +1,114 @@
+use rust_decimal::Decimal;
+
+use crate::easter::easter;
+use crate::julian::CalendarDate;
+
+/// A region whose public holidays are known to ``is_holiday``.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// The nationwide public holidays of Germany.
+    Germany,
+    /// The public holidays of metropolitan France.
+    France,
+}
+
+/// A holiday on a fixed ``(month, day)`` of every year.
+type FixedHoliday = (u8, u8, &'static str);
+
+/// A movable feast, a number of days away from Easter Sunday.
+type MovableFeast = (i64, &'static str);
+
+const GERMANY_FIXED: [FixedHoliday; 5] = [
+    (1, 1, "New Year's Day"),
+    (5, 1, "Labour Day"),
+    (10, 3, "German Unity Day"),
+    (12, 25, "Christmas Day"),
+    (12, 26, "Second Day of Christmas"),
+];
+
+const GERMANY_MOVABLE: [MovableFeast; 4] = [
+    (-2, "Good Friday"),
+    (1, "Easter Monday"),
+    (39, "Ascension Day"),
+    (50, "Whit Monday"),
+];
+
+const FRANCE_FIXED: [FixedHoliday; 8] = [
+    (1, 1, "New Year's Day"),
+    (5, 1, "Labour Day"),
+    (5, 8, "Victory in Europe Day"),
+    (7, 14, "Bastille Day"),
+    (8, 15, "Assumption of Mary"),
+    (11, 1, "All Saints' Day"),
+    (11, 11, "Armistice Day"),
+    (12, 25, "Christmas Day"),
+];
+
+const FRANCE_MOVABLE: [MovableFeast; 3] = [
+    (1, "Easter Monday"),
+    (39, "Ascension Day"),
+    (50, "Whit Monday"),
+];
+
+/// Returns the name of the public holiday falling on ``date`` in the given region, or ``None`` on a working day.\
+/// Both fixed-date holidays and the movable feasts tied to Easter (see ``easter``) are covered. The time of day is ignored.
+/// 
+/// **NOTE:** The present-day holidays of each region are applied to every year, regardless of when they were introduced.
+pub fn is_holiday(date: &CalendarDate, region: Region) -> Option<&'static str> {
+    let (fixed, movable): (&[FixedHoliday], &[MovableFeast]) = match region {
+        Region::Germany => (&GERMANY_FIXED, &GERMANY_MOVABLE),
+        Region::France => (&FRANCE_FIXED, &FRANCE_MOVABLE),
+    };
+
+    let day = date.day().floor();
+    if let Some(&(_, _, name)) = fixed.iter().find(|&&(m, d, _)| m == date.month() && Decimal::from(d) == day) {
+        return Some(name);
+    }
+
+    let days_from_easter = (date.with_day(day) - easter(date.year())).whole_days();
+    movable.iter()
+        .find(|&&(offset, _)| offset == days_from_easter)
+        .map(|&(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use crate::holidays::*;
+    use crate::julian::WeekDay;
+
+    #[test]
+    fn test_fixed_holidays() {
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 1, 1), Region::Germany), Some("New Year's Day"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 10, 3), Region::Germany), Some("German Unity Day"));
+        assert_eq!(is_holiday(&CalendarDate::new(2024, 12, dec!(26.75)), Region::Germany), Some("Second Day of Christmas"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 7, 14), Region::France), Some("Bastille Day"));
+
+        // Each region has its own holidays
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 7, 14), Region::Germany), None);
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 10, 3), Region::France), None);
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 12, 26), Region::France), None);
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 3, 15), Region::Germany), None);
+    }
+
+    #[test]
+    fn test_movable_feasts() {
+        // Easter Sunday fell on 2024 March 31st
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 3, 29), Region::Germany), Some("Good Friday"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 4, 1), Region::Germany), Some("Easter Monday"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 5, 9), Region::Germany), Some("Ascension Day"));
+        assert_eq!(is_holiday(&CalendarDate::new(2024, 5, dec!(20.5)), Region::France), Some("Whit Monday"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 3, 29), Region::France), None);
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2024, 3, 31), Region::Germany), None);
+
+        // Ascension Day is always a Thursday, here on 2000 June 1st
+        let ascension = CalendarDate::from_ymd(2000, 6, 1);
+        assert_eq!(is_holiday(&ascension, Region::France), Some("Ascension Day"));
+        assert_eq!(ascension.day_of_the_week(), WeekDay::Thursday);
+
+        // A movable feast can coincide with a fixed holiday, which takes precedence
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2008, 5, 1), Region::Germany), Some("Labour Day"));
+        assert_eq!(is_holiday(&CalendarDate::from_ymd(2008, 5, 12), Region::Germany), Some("Whit Monday"));
+    }
+}
@@ -0,0 +1,104 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+/// Interpolates between three equidistant tabular values ``y1``, ``y2`` and ``y3``,
+/// for ``n`` intervals from the central value ``y2``.
+/// 
+/// Taken from "Interpolation"
+/// 
+/// **NOTE:** ``n`` should lie between -1 and 1, preferably between -0.5 and 0.5.
+pub fn interpolate3(y1: Decimal, y2: Decimal, y3: Decimal, n: Decimal) -> Decimal {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+
+    y2 + n / dec!(2) * (a + b + n * c)
+}
+
+/// Interpolates between five equidistant tabular values ``y1`` through ``y5``,
+/// for ``n`` intervals from the central value ``y3``.
+/// 
+/// Taken from "Interpolation"
+/// 
+/// **NOTE:** ``n`` should lie between -1 and 1, preferably between -0.5 and 0.5.
+pub fn interpolate5(y1: Decimal, y2: Decimal, y3: Decimal, y4: Decimal, y5: Decimal, n: Decimal) -> Decimal {
+    // First, second, third and fourth differences
+    let (a, b, c, d) = (y2 - y1, y3 - y2, y4 - y3, y5 - y4);
+    let (e, f, g) = (b - a, c - b, d - c);
+    let (h, j) = (f - e, g - f);
+    let k = j - h;
+
+    let n2 = n * n;
+
+    y3 + n / dec!(2) * (b + c)
+        + n2 / dec!(2) * f
+        + n * (n2 - Decimal::ONE) / dec!(12) * (h + j)
+        + n2 * (n2 - Decimal::ONE) / dec!(24) * k
+}
+
+/// Returns the extremum ``(n, y)`` of the function through three equidistant tabular values ``y1``, ``y2`` and ``y3``,
+/// where ``n`` counts intervals from the central value ``y2``.
+/// 
+/// Taken from "Interpolation"
+/// 
+/// Returns ``None`` if the three values lie on a straight line, as there is no extremum.
+pub fn extremum(y1: Decimal, y2: Decimal, y3: Decimal) -> Option<(Decimal, Decimal)> {
+    let a = y2 - y1;
+    let b = y3 - y2;
+    let c = b - a;
+
+    if c.is_zero() {
+        return None;
+    }
+
+    let n = -(a + b) / (dec!(2) * c);
+    let y = y2 - (a + b) * (a + b) / (dec!(8) * c);
+
+    Some((n, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interpolation::*;
+
+    #[test]
+    fn test_interpolate3() {
+        // Example 3.a, the distance of Mars to the Earth on 1992 November 8 at 4h21m TD
+        let y = interpolate3(dec!(0.884226), dec!(0.877366), dec!(0.870531), dec!(0.18125));
+        assert_eq!(y.round_dp(6), dec!(0.876125));
+
+        assert_eq!(interpolate3(dec!(1), dec!(4), dec!(9), dec!(-1)), dec!(1));
+        assert_eq!(interpolate3(dec!(1), dec!(4), dec!(9), dec!(0.5)), dec!(6.25));
+    }
+
+    #[test]
+    fn test_interpolate5() {
+        // Example 3.d, the declination of Mars in arcseconds at 0h TD from 1973 February 25 to March 1,
+        // which becomes zero at n = -0.361413, on February 26 at 15h20m TD
+        let y = [dec!(-4281.23), dec!(-1692.31), dec!(967.02), dec!(3660.13), dec!(6346.33)];
+        let declination = interpolate5(y[0], y[1], y[2], y[3], y[4], dec!(-0.361413));
+        assert!(declination.abs() < dec!(0.001));
+
+        // Exact for polynomials up to the fourth degree, here y = x⁴ - 2x³ + x sampled at x = 0 through 4
+        let f = |x: Decimal| x * x * x * x - dec!(2) * x * x * x + x;
+        let y = [dec!(0), dec!(1), dec!(2), dec!(3), dec!(4)].map(f);
+
+        for n in [dec!(-0.5), dec!(0), dec!(0.25), dec!(1)] {
+            assert_eq!(interpolate5(y[0], y[1], y[2], y[3], y[4], n), f(dec!(2) + n));
+        }
+
+        // Agrees with three-point interpolation on a quadratic
+        let q = [dec!(9), dec!(4), dec!(1), dec!(0), dec!(1)];
+        assert_eq!(interpolate5(q[0], q[1], q[2], q[3], q[4], dec!(0.3)), interpolate3(q[1], q[2], q[3], dec!(0.3)));
+    }
+
+    #[test]
+    fn test_extremum() {
+        // Example 3.b, the least distance of Mars to the Sun in 1992 August, tabulated every 4 days from August 5.0
+        let (n, y) = extremum(dec!(1.3814294), dec!(1.3812213), dec!(1.3812453)).unwrap();
+        assert_eq!(n.round_dp(4), dec!(0.3966));
+        assert_eq!(y.round_dp(7), dec!(1.3812030));
+
+        assert_eq!(extremum(dec!(1), dec!(2), dec!(3)), None);
+    }
+}
@@ -1,11 +1,23 @@
 use rust_decimal::Decimal;
 use rust_decimal::dec;
 use rust_decimal::prelude::*;
-use std::convert::TryFrom;
-use std::error::Error;
-use std::ops::Sub;
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use core::ops::{Add, Neg, Sub};
+use core::error::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::dynamical::{ScaledJulianDay, TimeScale, delta_t};
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(u8)]
 pub enum WeekDay {
     Sunday = 0,
@@ -21,14 +33,37 @@ pub enum WeekDay {
 pub enum WeekDayError {
     InvalidDayNumber,
     NonIntegerDecimal,
+    /// The string is neither a full English day name nor a three-letter abbreviation.
+    InvalidName,
+}
+
+impl fmt::Display for WeekDayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::InvalidDayNumber => "day of the week must be between 0 (Sunday) and 6 (Saturday)",
+            Self::NonIntegerDecimal => "day of the week must be a non-negative integer",
+            Self::InvalidName => "expected an English day name such as \"Monday\" or \"mon\"",
+        };
+
+        f.write_str(msg)
+    }
 }
 
+impl Error for WeekDayError {}
+
 impl TryFrom<Decimal> for WeekDay {
     type Error = WeekDayError;
 
     fn try_from(d: Decimal) -> Result<Self, Self::Error> {
         let day = d.to_u8().ok_or(WeekDayError::NonIntegerDecimal)?;
+        WeekDay::try_from(day)
+    }
+}
+
+impl TryFrom<u8> for WeekDay {
+    type Error = WeekDayError;
 
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
         match day {
             1 => Ok(Self::Monday),
             2 => Ok(Self::Tuesday),
@@ -42,20 +77,173 @@ impl TryFrom<Decimal> for WeekDay {
     }
 }
 
+impl WeekDay {
+    /// Returns the following day of the week, wrapping from ``Saturday`` around to ``Sunday``.
+    pub fn next(self) -> WeekDay {
+        WeekDay::try_from((self as u8 + 1) % 7).unwrap()
+    }
+
+    /// Returns the preceding day of the week, wrapping from ``Sunday`` around to ``Saturday``.
+    pub fn previous(self) -> WeekDay {
+        WeekDay::try_from((self as u8 + 6) % 7).unwrap()
+    }
+
+    /// Returns the seven days of the week in order, beginning with ``start``.
+    pub fn week_starting(start: WeekDay) -> [WeekDay; 7] {
+        let mut days = [start; 7];
+        for i in 1..7 {
+            days[i] = days[i - 1].next();
+        }
+        days
+    }
+
+    /// Returns the ISO 8601 day number, from ``Monday = 1`` to ``Sunday = 7``.
+    /// 
+    /// **NOTE:** This differs from the ``u8`` representation of ``WeekDay``, which starts at ``Sunday = 0``.
+    pub fn number(self) -> u8 {
+        match self {
+            Self::Sunday => 7,
+            day => day as u8,
+        }
+    }
+}
+
+impl FromStr for WeekDay {
+    type Err = WeekDayError;
+
+    /// Parses a full English day name (e.g. ``"Monday"``) or a three-letter abbreviation (e.g. ``"mon"``).\
+    /// Parsing is case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sunday" | "sun" => Ok(Self::Sunday),
+            "monday" | "mon" => Ok(Self::Monday),
+            "tuesday" | "tue" => Ok(Self::Tuesday),
+            "wednesday" | "wed" => Ok(Self::Wednesday),
+            "thursday" | "thu" => Ok(Self::Thursday),
+            "friday" | "fri" => Ok(Self::Friday),
+            "saturday" | "sat" => Ok(Self::Saturday),
+            _ => Err(WeekDayError::InvalidName),
+        }
+    }
+}
+
+impl fmt::Display for WeekDay {
+    /// Renders the full English day name, e.g. ``Monday``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        };
+
+        f.write_str(name)
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Calendar {
     Gregorian,
     Julian,
 }
 
+/// The era of a year in historical numbering, which has no year 0: 1 BC is directly followed by AD 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Era {
+    BC,
+    AD,
+}
+
 #[derive(Debug)]
 pub enum CalendarDateError {
     InvalidJulianDay,
+    /// The date cannot be represented by the target type, e.g. it falls outside of its supported range.
+    UnrepresentableDate,
+}
+
+impl fmt::Display for CalendarDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::InvalidJulianDay => "Julian Day must be non-negative",
+            Self::UnrepresentableDate => "date cannot be represented by the target type",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+impl Error for CalendarDateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateValidationError {
+    MonthOutOfRange,
+    DayOutOfRange,
+    /// The day was dropped by the Gregorian reform and never occurred, e.g. 1582 October 5th through 14th.
+    DroppedDay,
+    /// The day of the year must be between 1 and 365, or 366 in a leap year and 355 in 1582.
+    DayOfYearOutOfRange,
+    /// The historical year must be at least 1 and fit in an ``i32``.
+    YearOutOfRange,
+    /// The year, month or day was never set on a ``CalendarDateBuilder``.
+    MissingField,
+}
+
+impl fmt::Display for DateValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::MonthOutOfRange => "month must be between 1 and 12",
+            Self::DayOutOfRange => "day does not exist in the given month",
+            Self::DroppedDay => "day was dropped by the Gregorian reform and never occurred",
+            Self::DayOfYearOutOfRange => "day of the year does not exist in the given year",
+            Self::YearOutOfRange => "historical year must be between 1 and 2147483647",
+            Self::MissingField => "year, month and day must all be set",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+impl Error for DateValidationError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    Malformed,
+    MonthOutOfRange,
+    DayOutOfRange,
+    TimeOutOfRange,
+    /// The JSON text is not of the form written by ``to_json``.
+    InvalidJson,
+    /// The string matches none of the formats accepted by ``CalendarDate::try_from``.
+    UnrecognizedFormat,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::Malformed => "expected a date of the form YYYY-MM-DD with an optional Thh:mm[:ss] time",
+            Self::MonthOutOfRange => "month must be between 1 and 12",
+            Self::DayOutOfRange => "day does not exist in the given month",
+            Self::TimeOutOfRange => "time must be between 00:00:00 and 23:59:59",
+            Self::InvalidJson => "expected a JSON number, or an object with year, month, day and optional calendar fields",
+            Self::UnrecognizedFormat => "expected a date of the form YYYY-MM-DD, YYYY/MM/DD, DD-MM-YYYY or YYYY-DDD",
+        };
+
+        f.write_str(msg)
+    }
 }
 
+impl Error for ParseError {}
+
+/// With the ``serde`` feature, a JulianDay is serialized as a single decimal number.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct JulianDay {
+    #[cfg_attr(feature = "serde", serde(with = "rust_decimal::serde::float"))]
     pub day: Decimal
 }
 
@@ -72,248 +260,3151 @@ impl From<CalendarDate> for JulianDay {
 impl From<&CalendarDate> for JulianDay {
     /// Converts a &CalendarDate into a JulianDay.
     fn from(cd: &CalendarDate) -> Self {
-        let mut y = cd.y;
-        let mut m = cd.m;
-        let d = cd.d;
+        julian_day(cd.y, cd.m, cd.d, cd.get_calendar())
+    }
+}
 
-        if cd.m == 1 || cd.m == 2 {
-            y = y - 1;
-            m = m + 12;
-        }
+/// The offset ``TAI - UTC`` in seconds, from the Unix timestamp (UTC) at which it took effect.
+/// 
+/// **NOTE:** This table is static and ends with the leap second inserted at the end of 2016.
+/// It must be extended whenever the IERS announces a new leap second.
+const LEAP_SECONDS: [(i64, i64); 28] = [
+    (63072000, 10), // 1972-01-01
+    (78796800, 11), // 1972-07-01
+    (94694400, 12), // 1973-01-01
+    (126230400, 13), // 1974-01-01
+    (157766400, 14), // 1975-01-01
+    (189302400, 15), // 1976-01-01
+    (220924800, 16), // 1977-01-01
+    (252460800, 17), // 1978-01-01
+    (283996800, 18), // 1979-01-01
+    (315532800, 19), // 1980-01-01
+    (362793600, 20), // 1981-07-01
+    (394329600, 21), // 1982-07-01
+    (425865600, 22), // 1983-07-01
+    (489024000, 23), // 1985-07-01
+    (567993600, 24), // 1988-01-01
+    (631152000, 25), // 1990-01-01
+    (662688000, 26), // 1991-01-01
+    (709948800, 27), // 1992-07-01
+    (741484800, 28), // 1993-07-01
+    (773020800, 29), // 1994-07-01
+    (820454400, 30), // 1996-01-01
+    (867715200, 31), // 1997-07-01
+    (915148800, 32), // 1999-01-01
+    (1136073600, 33), // 2006-01-01
+    (1230768000, 34), // 2009-01-01
+    (1341100800, 35), // 2012-07-01
+    (1435708800, 36), // 2015-07-01
+    (1483228800, 37), // 2017-01-01
+];
 
-        let b = match cd.get_calendar() {
-            Calendar::Gregorian => {
-                let a = (Decimal::from(y) / dec!(100.0)).floor();
-                dec!(2.0) - a + (a / dec!(4.0)).floor()
-            },
-            Calendar::Julian => dec!(0.0)
-        };
+/// A JulianDay beyond the end of the year ``i32::MAX``, after which no CalendarDate can be represented.
+const MAX_JULIAN_DAY: Decimal = dec!(1000000000000);
 
-        let j = (dec!(365.25) * (Decimal::from(y) + dec!(4716.0))).floor() + (dec!(30.6001) * (Decimal::from(m) + dec!(1.0))).floor() + d + b - dec!(1524.5);
-        JulianDay::new(j)
-    }
+/// The JulianDay of Rata Die 0, the day before 0001 January 1st of the proleptic Gregorian calendar, at 0h.
+const RATA_DIE_EPOCH: Decimal = dec!(1721424.5);
+
+/// Returns ``TAI - UTC`` in seconds at the given Unix timestamp (UTC).\
+/// Before 1972, when UTC was not yet kept within whole seconds of TAI, the initial offset of 10 seconds is used.
+fn tai_minus_utc(utc: i64) -> i64 {
+    LEAP_SECONDS.iter()
+        .rev()
+        .find(|&&(since, _)| utc >= since)
+        .map_or(LEAP_SECONDS[0].1, |&(_, offset)| offset)
+}
+
+/// Converts a year, month and day into a JulianDay, using the formula of the given calendar.
+fn julian_day(y: i32, m: u8, d: Decimal, calendar: Calendar) -> JulianDay {
+    let (y, m) = if m == 1 || m == 2 {
+        (y - 1, m + 12)
+    } else {
+        (y, m)
+    };
+
+    let b = match calendar {
+        Calendar::Gregorian => {
+            let a = (Decimal::from(y) / dec!(100.0)).floor();
+            dec!(2.0) - a + (a / dec!(4.0)).floor()
+        },
+        Calendar::Julian => dec!(0.0)
+    };
+
+    let j = (dec!(365.25) * (Decimal::from(y) + dec!(4716.0))).floor() + (dec!(30.6001) * (Decimal::from(m) + dec!(1.0))).floor() + d + b - dec!(1524.5);
+    JulianDay::new(j)
 }
 
 impl JulianDay {
+    /// The standard epoch J2000.0, 2000 January 1st at 12h TD.
+    pub const J2000: JulianDay = JulianDay { day: dec!(2451545.0) };
+
+    /// The Unix epoch, 1970 January 1st at 0h UTC.
+    pub const UNIX_EPOCH: JulianDay = JulianDay { day: dec!(2440587.5) };
+
+    /// The epoch of the Modified Julian Day, 1858 November 17th at 0h.
+    pub const MJD_EPOCH: JulianDay = JulianDay { day: dec!(2400000.5) };
+
+    /// The first day of the Gregorian calendar, 1582 October 15th at 0h.
+    pub const GREGORIAN_REFORM: JulianDay = JulianDay { day: dec!(2299160.5) };
+
+    /// The first day of the Gregorian calendar in Great Britain and its colonies, 1752 September 14th at 0h.\
+    /// See ``CalendarDate::with_reform``.
+    pub const BRITISH_REFORM: JulianDay = JulianDay { day: dec!(2361221.5) };
+
     pub fn new(day: Decimal) -> Self {
         Self { day }
     }
-}
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct CalendarDate {
-    y: i32,
-    m: u8,
-    d: Decimal,
-}
+    /// Creates a JulianDay from a date expressed in the Gregorian calendar, regardless of the 1582 reform date.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn from_gregorian(y: i32, m: u8, d: Decimal) -> JulianDay {
+        julian_day(y, m, d, Calendar::Gregorian)
+    }
 
-impl CalendarDate {
-    /// Does not validate input. The year, month and day MUST be a valid date.
-    /// Refer to your local calendar if uncertain
-    pub fn new(y: i32, m: u8, d: Decimal) -> Self {
-        Self { y, m, d }
+    /// Creates a JulianDay from a date expressed in the Julian calendar, regardless of the 1582 reform date.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn from_julian(y: i32, m: u8, d: Decimal) -> JulianDay {
+        julian_day(y, m, d, Calendar::Julian)
     }
 
-    /// Determines if this calendar date falls on a leap year.
+    /// Returns the integer part of this JulianDay, i.e. the Julian Day Number of the preceding noon.
+    pub fn integer_part(&self) -> Decimal {
+        self.day.floor()
+    }
+
+    /// Returns the fractional part of this JulianDay in ``[0, 1)``, measured from noon.
     /// 
-    /// **NOTE**: The way a leap year is calculated depends on the calendar in use at the time.
-    /// - If ``CalendarDate`` occurs strictly before 1582 October 15th, leap years will be calculated according to the Julian Calendar
-    /// - If ``CalendarDate`` occurs during or after 1582 October 15th, leap years will be calculated according to the Gregorian Calendar
-    pub fn leap_year(&self) -> bool {
-        return match self.get_calendar() {
-            Calendar::Gregorian => {
-                self.y % 4 == 0
-            },
-            Calendar::Julian => {
-                (self.y % 4 == 0) && ((self.y % 100 != 0) || (self.y % 400 == 0))
-            }
-        }
+    /// **NOTE:** Julian days begin at noon, so a fraction of ``0.5`` is midnight. Refer to ``JulianDay::civil_fraction``.
+    pub fn fraction(&self) -> Decimal {
+        self.day - self.day.floor()
     }
 
-    /// Returns the days between two CalendarDate objects.\
-    /// Defined as: ``|lhs - rhs|``.
-    pub fn days_between(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
-        return Self::difference(lhs, rhs).abs();
+    /// Returns the fraction of the civil day in ``[0, 1)``, measured from midnight.\
+    /// This is offset by half a day from ``JulianDay::fraction``.
+    pub fn civil_fraction(&self) -> Decimal {
+        let civil = self.day + dec!(0.5);
+        civil - civil.floor()
     }
 
-    /// Returns the day of the week corresponding to this CalendarDate.
+    /// Returns the day of the week of the civil day this JulianDay falls on, counted from midnight.
     /// 
-    /// **NOTE:** The week was not modified in any way by the Gregorian reform of the Julian calendar.\
-    /// Thus, in 1582, ``Thursday October 4`` was followed by ``Friday October 15``.
-    pub fn day_of_the_week(&self) -> WeekDay {
-        let date_0hr = CalendarDate {y: self.y, m: self.m, d: self.d.round()};
-        let jd = JulianDay::from(date_0hr);
-        println!("{}", jd.day);
+    /// Taken from "Day of the Week"
+    pub fn weekday(&self) -> WeekDay {
+        // Floor before reducing so that the remainder is always a whole number in 0..=6
+        let day = (self.day + dec!(1.5)).floor() % dec!(7);
+        let day = if day.is_sign_negative() { day + dec!(7) } else { day };
 
-        let day = (jd.day + dec!(1.5)) % dec!(7);
+        WeekDay::try_from(day.to_u8().unwrap()).unwrap()
+    }
 
-        WeekDay::try_from(day).unwrap()
+    /// Returns this JulianDay rounded to ``places`` decimal places, using bankers' rounding.
+    pub fn round_to(&self, places: u32) -> JulianDay {
+        JulianDay::new(self.day.round_dp(places))
     }
 
-    /// Returns an integer between and including 1 and 365 (or 366 if date corresponds to a leap year).\
-    /// This represents the current day as an offset of the current year.
-    pub fn day_of_the_year(&self) -> i32 {
-        let k = Decimal::from(match self.leap_year() {
-            true => 1,
-            false => 2,
-        });
+    /// Renders this JulianDay with exactly ``places`` decimal places, e.g. ``2436116.31000`` for 5 places.\
+    /// Equivalent to ``format!("{:.places$}", jd)``.
+    pub fn to_string_fixed(self, places: u32) -> String {
+        format!("{:.*}", places as usize, self)
+    }
 
-        let m_d = Decimal::from(self.m);
-        let d_d = Decimal::from(self.d);
+    /// Encodes this JulianDay as a JSON number, e.g. ``2451545.0``, without depending on serde.
+    pub fn to_json(self) -> String {
+        format!("{}", self.day)
+    }
 
-        let n = ((dec!(275) * m_d) / dec!(9)).floor() - k * ((m_d + dec!(9)) / dec!(12)).floor() + d_d - dec!(30);
-        return n.to_i32().unwrap()
+    /// Decodes a JulianDay from a JSON number, the inverse of ``JulianDay::to_json``.
+    pub fn from_json(s: &str) -> Result<JulianDay, ParseError> {
+        let s = s.trim();
+        Decimal::from_str(s)
+            .or_else(|_| Decimal::from_scientific(s))
+            .map(JulianDay::new)
+            .map_err(|_| ParseError::InvalidJson)
     }
 
-    /// Returns the difference between two CalendarDate objects.\
-    /// Defined as: ``lhs - rhs``
-    pub fn difference(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
-        let lhs_jd = JulianDay::from(lhs);
-        let rhs_jd = JulianDay::from(rhs);
-        return lhs_jd.day - rhs_jd.day;
+    /// Determines if two JulianDay objects are within ``tol`` days of each other.
+    pub fn approx_eq(&self, other: &JulianDay, tol: Decimal) -> bool {
+        (self.day - other.day).abs() <= tol
     }
 
-    /// Determines what calendar system the current CalendarDate falls under
-    pub fn get_calendar(&self) -> Calendar {
-        let year_is_julian = self.y < 1582;
-        let month_is_julian = self.y == 1582 && self.m < 10;
-        let day_is_julian = self.y == 1582 && self.m == 10 && self.d < dec!(15.0);
+    /// Returns the time elapsed since the epoch J2000.0 (2000 January 1.5) in Julian centuries of 36525 days.\
+    /// Defined as: ``T = (JD - 2451545.0) / 36525``.
+    pub fn centuries_since_j2000(&self) -> Decimal {
+        (self.day - Self::J2000.day) / dec!(36525)
+    }
 
-        return if year_is_julian || month_is_julian || day_is_julian {
-            Calendar::Julian
-        } else {
-            Calendar::Gregorian
-        }
+    /// Returns the time elapsed since the epoch J2000.0 in Julian millennia of 365250 days.\
+    /// Defined as: ``τ = T / 10``.
+    pub fn millennia_since_j2000(&self) -> Decimal {
+        self.centuries_since_j2000() / dec!(10)
     }
-}
 
-impl TryFrom<JulianDay> for CalendarDate {
-    type Error = CalendarDateError;
+    /// Returns the Julian epoch of this JulianDay, e.g. ``2000.0`` for J2000.0.\
+    /// Defined as: ``J = 2000.0 + (JD - 2451545.0) / 365.25``.
+    pub fn to_julian_epoch(self) -> Decimal {
+        dec!(2000.0) + (self.day - Self::J2000.day) / dec!(365.25)
+    }
 
-    /// Taken from "Calculation of the Calendar Date from the JD"
+    /// Creates a JulianDay from a Julian epoch, the inverse of ``JulianDay::to_julian_epoch``.
+    pub fn from_julian_epoch(epoch: Decimal) -> JulianDay {
+        JulianDay::new(Self::J2000.day + (epoch - dec!(2000.0)) * dec!(365.25))
+    }
+
+    /// Returns the Besselian epoch of this JulianDay, e.g. ``1950.0`` for B1950.0.\
+    /// Defined as: ``B = 1900.0 + (JD - 2415020.31352) / 365.242198781``, counting tropical years.
+    pub fn to_besselian_epoch(self) -> Decimal {
+        dec!(1900.0) + (self.day - dec!(2415020.31352)) / dec!(365.242198781)
+    }
+
+    /// Creates a JulianDay from a Besselian epoch, the inverse of ``JulianDay::to_besselian_epoch``.
+    pub fn from_besselian_epoch(epoch: Decimal) -> JulianDay {
+        JulianDay::new(dec!(2415020.31352) + (epoch - dec!(1900.0)) * dec!(365.242198781))
+    }
+
+    /// Creates a JulianDay from a Unix timestamp, in seconds since 1970 January 1st at 0h UTC (JD 2440587.5).
     /// 
-    /// **NOTE:** A valid julian day does not neccessarily correspond to a valid calendar date
-    /// We require jd >= 0 for the conversion to be successful
-    fn try_from(j: JulianDay) -> Result<Self, Self::Error> {
-        if j.day < Decimal::ZERO {
-            return Err(CalendarDateError::InvalidJulianDay)
+    /// **NOTE:** Unix time ignores leap seconds, every day is counted as exactly 86400 seconds.
+    pub fn from_unix_timestamp(secs: i64) -> JulianDay {
+        JulianDay::UNIX_EPOCH + Decimal::from(secs) / dec!(86400)
+    }
+
+    /// Returns the current instant according to the system clock, in Universal Time (UTC).
+    /// 
+    /// **NOTE:** Panics if the system clock is set before the Unix epoch.
+    #[cfg(feature = "std-clock")]
+    pub fn now() -> JulianDay {
+        let elapsed = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+        let secs = Decimal::from(elapsed.as_secs()) + Decimal::new(i64::from(elapsed.subsec_nanos()), 9);
+
+        JulianDay::UNIX_EPOCH + secs / dec!(86400)
+    }
+
+    /// Returns the Unix timestamp corresponding to this JulianDay, truncated to whole seconds.
+    /// 
+    /// Returns ``None`` for instants before the Unix epoch, or beyond the range of ``i64`` seconds.
+    pub fn to_unix_timestamp(self) -> Option<i64> {
+        if self.day < Self::UNIX_EPOCH.day {
+            return None;
         }
 
-        let jd = j.day + dec!(0.5);
-        
-        let z = jd.floor();
-        let f = jd - z;
-    
-        let a = if z < dec!(2299161.0) {
-            z
-        } else {
-            let alpha = ((z - dec!(1867216.25)) / dec!(36524.25)).floor();
-            z + dec!(1.0) + alpha - (alpha / dec!(4.0)).floor()
-        };
-    
-        let b = a + dec!(1524.0);
-        let c = ((b - dec!(122.1)) / dec!(365.25)).floor();
-        let d = (dec!(365.25) * c).floor();
-        let e = ((b - d) / dec!(30.6001)).floor();
-    
-        let day = b - d - (dec!(30.6001) * e).floor() + f;
-    
-        let month = if e < dec!(14.0) {
-            e - dec!(1.0)
-        } else {
-            e - dec!(13.0)
-        };
-    
-        let year = if month > dec!(2.0) {
-            c - dec!(4716.0)
-        } else {
-            c - dec!(4715.0)
-        };
-    
-        Ok(CalendarDate::new(year.trunc().to_i32().unwrap(), month.trunc().to_u8().unwrap(), day))
+        (self - Self::UNIX_EPOCH).checked_mul(dec!(86400))?.trunc().to_i64()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::julian::*;
+    /// Creates a JulianDay in International Atomic Time (TAI) from a Unix timestamp, adding the leap seconds in effect.
+    /// 
+    /// **NOTE:** The leap second table is static, refer to ``LEAP_SECONDS``.
+    pub fn from_unix_timestamp_tai(secs: i64) -> JulianDay {
+        JulianDay::from_unix_timestamp(secs + tai_minus_utc(secs))
+    }
 
-    #[test]
-    fn get_calendar_test() {
-        let date = CalendarDate::new(1957, 10, dec!(4.81));
-        assert_eq!(date.get_calendar(), Calendar::Gregorian);
-        
-        let date = CalendarDate::new(333, 1, dec!(27.5));
-        assert_eq!(date.get_calendar(), Calendar::Julian);
+    /// Returns the Unix timestamp corresponding to this JulianDay in International Atomic Time (TAI), removing the leap seconds in effect.\
+    /// The inverse of ``JulianDay::from_unix_timestamp_tai``, truncated to whole seconds.
+    /// 
+    /// Returns ``None`` for instants before the Unix epoch, or beyond the range of ``i64`` seconds.
+    /// 
+    /// **NOTE:** Unix time cannot represent an inserted leap second, which maps onto the first second of the following day.
+    pub fn to_unix_timestamp_utc(self) -> Option<i64> {
+        if self.day < Self::UNIX_EPOCH.day {
+            return None;
+        }
 
-        let date = CalendarDate::new(1582, 10, dec!(14.9));
-        assert_eq!(date.get_calendar(), Calendar::Julian);
+        // Round away the residue of dividing by 86400 before truncating to whole seconds
+        let tai = (self - Self::UNIX_EPOCH).checked_mul(dec!(86400))?.round_dp(6).trunc().to_i64()?;
+        let offset = LEAP_SECONDS.iter()
+            .rev()
+            .find(|&&(since, offset)| tai >= since + offset)
+            .map_or(LEAP_SECONDS[0].1, |&(_, offset)| offset);
 
-        let date = CalendarDate::new(1582, 10, dec!(15.0));
-        assert_eq!(date.get_calendar(), Calendar::Gregorian);
+        Some(tai - offset)
     }
 
-    #[test]
-    fn julian_day_test() {
-        // Example 7.a
-        let date = CalendarDate::new(1957, 10, dec!(4.81));
-        assert_eq!(JulianDay::from(date).day, dec!(2436116.31));
+    /// Converts this JulianDay from Universal Time (UT) to Dynamical Time (TD).\
+    /// Defined as: ``TD = UT + ΔT``, where ΔT is estimated for ``year_hint``.
+    /// 
+    /// **NOTE:** ΔT for the far past and the future is an extrapolation, refer to ``dynamical::delta_t``.
+    pub fn to_dynamical_time(self, year_hint: i32) -> JulianDay {
+        self + delta_t(year_hint) / dec!(86400)
+    }
 
-        // Example 7.b
-        let date = CalendarDate::new(333, 1, dec!(27.5));
-        assert_eq!(JulianDay::from(date).day, dec!(1842713.0));
+    /// Tags this JulianDay with the time scale it is expressed in, so that it can be converted with ``ScaledJulianDay::convert_scale``.
+    pub fn in_scale(self, scale: TimeScale) -> ScaledJulianDay {
+        ScaledJulianDay { jd: self, scale }
+    }
 
-        // Gregorian dates
-        let g1 = CalendarDate::new(2000, 1, dec!(1.5));
-        let g2 = CalendarDate::new(1999, 1, dec!(1.0));
-        let g3 = CalendarDate::new(1987, 1, dec!(27.0));
-        let g4 = CalendarDate::new(1600, 12, dec!(31.0));
+    /// Returns the Modified Julian Day corresponding to this JulianDay.\
+    /// Defined as: ``MJD = JD - 2400000.5``.
+    /// 
+    /// **NOTE:** Unlike the Julian Day, the Modified Julian Day starts at midnight.
+    pub fn to_modified(self) -> Decimal {
+        self - Self::MJD_EPOCH
+    }
 
-        assert_eq!(JulianDay::from(g1).day, dec!(2451545.0));
-        assert_eq!(JulianDay::from(g2).day, dec!(2451179.5));
-        assert_eq!(JulianDay::from(g3).day, dec!(2446822.5));
-        assert_eq!(JulianDay::from(g4).day, dec!(2305812.5));
+    /// Creates a JulianDay from a Modified Julian Day.\
+    /// Defined as: ``JD = MJD + 2400000.5``.
+    pub fn from_modified(mjd: Decimal) -> JulianDay {
+        JulianDay::MJD_EPOCH + mjd
+    }
+}
 
-        // Julian dates
-        let j1 = CalendarDate::new(837, 4, dec!(10.3));
-        let j2 = CalendarDate::new(-123, 12, dec!(31.0));
-        let j3 = CalendarDate::new(-1000, 7, dec!(12.5));
-        let j4 = CalendarDate::new(-4712, 1, dec!(1.5));
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModifiedJulianDay(pub Decimal);
 
-        assert_eq!(JulianDay::from(j1).day, dec!(2026871.8));
-        assert_eq!(JulianDay::from(j2).day, dec!(1676496.5));
-        assert_eq!(JulianDay::from(j3).day, dec!(1356001.0));
-        assert_eq!(JulianDay::from(j4).day, dec!(0.0));
+impl From<JulianDay> for ModifiedJulianDay {
+    fn from(jd: JulianDay) -> Self {
+        ModifiedJulianDay(jd.to_modified())
     }
+}
 
-    #[test]
-    fn test_get_calendar_date() {
-        let jd = JulianDay::new(dec!(2436116.31));
-        let cd = CalendarDate::try_from(jd).unwrap();
+impl From<ModifiedJulianDay> for JulianDay {
+    fn from(mjd: ModifiedJulianDay) -> Self {
+        JulianDay::from_modified(mjd.0)
+    }
+}
 
-        assert_eq!(cd, CalendarDate::new(1957, 10, dec!(4.81)));
+impl Add<Decimal> for JulianDay {
+    type Output = JulianDay;
+
+    /// Advances this JulianDay by a (possibly fractional) number of days.
+    fn add(self, days: Decimal) -> Self::Output {
+        JulianDay::new(self.day + days)
     }
+}
 
-    #[test]
-    fn test_interval_between() {
-        let first = CalendarDate::new(1910, 4, dec!(20));
-        let second = CalendarDate::new(1986, 2, dec!(9));
-        
-        assert_eq!(CalendarDate::days_between(&first, &second), dec!(27689));
+impl Sub<Decimal> for JulianDay {
+    type Output = JulianDay;
+
+    /// Moves this JulianDay back by a (possibly fractional) number of days.
+    fn sub(self, days: Decimal) -> Self::Output {
+        JulianDay::new(self.day - days)
     }
+}
 
-    #[test]
-    fn test_day_of_the_week() {
-        let date = CalendarDate::new(1954, 6, dec!(30));
-        assert_eq!(date.day_of_the_week(), WeekDay::Wednesday);
+impl Sub for JulianDay {
+    type Output = Decimal;
+
+    /// Returns the signed number of days between two JulianDay objects.
+    fn sub(self, rhs: JulianDay) -> Self::Output {
+        self.day - rhs.day
     }
+}
 
-    #[test]
-    fn test_day_of_the_year() {
-        let date1 = CalendarDate::new(1978, 11, dec!(14));
-        let date2 = CalendarDate::new(1988, 4, dec!(22));
+impl fmt::Display for JulianDay {
+    /// Renders the day number, honouring the formatter's precision, e.g. ``{:.5}``.\
+    /// The alternate flag ``{:#}`` appends a trailing `` JD`` label.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.day, f)?;
 
-        assert_eq!(date1.day_of_the_year(), 318);
-        assert_eq!(date2.day_of_the_year(), 113);
+        if f.alternate() {
+            f.write_str(" JD")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// With the ``serde`` feature, a CalendarDate is serialized as an object with ``year``, ``month`` and ``day`` fields.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CalendarDate {
+    #[cfg_attr(feature = "serde", serde(rename = "year"))]
+    y: i32,
+    #[cfg_attr(feature = "serde", serde(rename = "month"))]
+    m: u8,
+    #[cfg_attr(feature = "serde", serde(rename = "day", with = "rust_decimal::serde::float"))]
+    d: Decimal,
+    /// Forces a calendar system. When ``None``, the calendar is decided by the reform date.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    calendar: Option<Calendar>,
+    /// The first day of the Gregorian calendar. When ``None``, this is 1582 October 15th.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    reform: Option<JulianDay>,
+}
+
+impl CalendarDate {
+    /// Does not validate input. The year, month and day MUST be a valid date.
+    /// Refer to your local calendar if uncertain
+    pub fn new(y: i32, m: u8, d: Decimal) -> Self {
+        Self { y, m, d, calendar: None, reform: None }
+    }
+
+    /// Creates a CalendarDate at 0h of a whole day, without wrapping the day into a Decimal.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn from_ymd(y: i32, m: u8, d: u8) -> Self {
+        Self::new(y, m, Decimal::from(d))
+    }
+
+    /// Creates a CalendarDate in the given calendar, regardless of the 1582 reform date.\
+    /// This allows proleptic Gregorian dates before 1582, or proleptic Julian dates after it.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn new_in(y: i32, m: u8, d: Decimal, calendar: Calendar) -> Self {
+        Self { y, m, d, calendar: Some(calendar), reform: None }
+    }
+
+    /// Creates a CalendarDate from a historically numbered year.\
+    /// Years are otherwise astronomically numbered, where 1 BC is year 0, 2 BC is year -1, and so on.
+    /// 
+    /// Fails if ``year`` is 0, as historical numbering has no year 0, or if it does not fit in an ``i32``.\
+    /// The month and day are validated as with ``CalendarDate::try_new``.
+    pub fn from_historical(era: Era, year: u32, m: u8, d: Decimal) -> Result<CalendarDate, DateValidationError> {
+        let year = match i32::try_from(year) {
+            Ok(year) if year != 0 => year,
+            _ => return Err(DateValidationError::YearOutOfRange),
+        };
+
+        let y = match era {
+            Era::BC => 1 - year,
+            Era::AD => year,
+        };
+
+        Self::try_new(y, m, d)
+    }
+
+    /// Returns the ``(era, year)`` of this CalendarDate in historical numbering.\
+    /// See ``CalendarDate::from_historical``.
+    pub fn to_historical(self) -> (Era, u32) {
+        if self.y > 0 {
+            (Era::AD, self.y.unsigned_abs())
+        } else {
+            (Era::BC, (1 - self.y).unsigned_abs())
+        }
+    }
+
+    /// Returns the same instant expressed in the given calendar, i.e. the resulting date corresponds to the same JulianDay.\
+    /// For example, converting 1582 October 4th into the proleptic Gregorian calendar gives 1582 October 14th.
+    /// 
+    /// **NOTE:** Panics if the date falls before JulianDay 0, as it cannot be converted back into a CalendarDate.
+    pub fn to_calendar(self, calendar: Calendar) -> Self {
+        self.checked_to_calendar(calendar).unwrap()
+    }
+
+    /// Returns the same instant expressed in the given calendar as with ``CalendarDate::to_calendar``,
+    /// or ``None`` if the date falls before JulianDay 0.
+    pub fn checked_to_calendar(self, calendar: Calendar) -> Option<Self> {
+        from_julian_day(JulianDay::from(self), Some(calendar), self.reform).ok()
+    }
+
+    /// Returns the same year, month and day, interpreted in the given calendar.
+    /// 
+    /// **NOTE:** The year, month and day are kept as is, so the resulting date generally corresponds to a different JulianDay.
+    pub fn with_calendar(self, calendar: Calendar) -> Self {
+        Self { calendar: Some(calendar), ..self }
+    }
+
+    /// Returns the same year, month and day, with the calendar decided by another reform date than 1582 October 15th,
+    /// e.g. ``JulianDay::BRITISH_REFORM`` for British historical dates.\
+    /// ``reform`` is the first day of the Gregorian calendar, at 0h. The days between the last Julian day and ``reform`` never occurred.
+    /// 
+    /// **NOTE:** A calendar forced with ``CalendarDate::new_in`` or ``CalendarDate::with_calendar`` takes precedence over the reform date.
+    pub fn with_reform(self, reform: JulianDay) -> Self {
+        Self { reform: Some(reform), ..self }
+    }
+
+    /// Returns the first day of the Gregorian calendar for this CalendarDate, see ``CalendarDate::with_reform``.
+    pub fn get_reform(&self) -> JulianDay {
+        self.reform.unwrap_or(JulianDay::GREGORIAN_REFORM)
+    }
+
+    /// Converts a JulianDay into a CalendarDate, switching from the Julian to the Gregorian calendar on ``reform`` rather than on 1582 October 15th.
+    /// 
+    /// **NOTE:** We require jd >= 0 for the conversion to be successful, as with ``CalendarDate::try_from``.
+    pub fn from_julian_day_with_reform(jd: JulianDay, reform: JulianDay) -> Result<CalendarDate, CalendarDateError> {
+        from_julian_day(jd, None, Some(reform))
+    }
+
+    /// Converts a JulianDay into a whole-day CalendarDate at 0h, rounding to the nearest midnight.\
+    /// An instant from noon onwards thus rounds up to the next day, e.g. JulianDay 2436116.49 (1957 October 4.99) gives 1957 October 5.
+    /// 
+    /// **NOTE:** The date is renormalized through the JulianDay, so the days dropped in 1582 October are skipped.\
+    /// We require the rounded JulianDay to be >= 0 for the conversion to be successful, as with ``CalendarDate::try_from``.
+    pub fn from_julian_day_rounded(jd: JulianDay) -> Result<CalendarDate, CalendarDateError> {
+        let civil = (jd.day + dec!(0.5)).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero);
+        CalendarDate::try_from(JulianDay::new(civil - dec!(0.5)))
+    }
+
+    /// Returns the current civil date in Universal Time (UTC) according to the system clock, at 0h.\
+    /// This may differ from the local date by a day, depending on the time zone.
+    /// 
+    /// **NOTE:** Panics if the system clock is set before the Unix epoch.
+    #[cfg(feature = "std-clock")]
+    pub fn today() -> CalendarDate {
+        let now = CalendarDate::try_from(JulianDay::now()).unwrap();
+        CalendarDate { d: now.d.floor(), ..now }
+    }
+
+    /// Returns the same year and month with the given day, keeping the calendar in use.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn with_day(self, d: Decimal) -> Self {
+        Self { d, ..self }
+    }
+
+    /// Returns a builder to construct a CalendarDate from named fields.
+    pub fn builder() -> CalendarDateBuilder {
+        CalendarDateBuilder::default()
+    }
+
+    /// Validates the year, month and day before creating a CalendarDate.
+    /// 
+    /// The day may carry a fractional part, but must lie within the month according to the calendar in use at the time.\
+    /// The days dropped by the Gregorian reform (1582 October 5th through 14th) are rejected.
+    pub fn try_new(y: i32, m: u8, d: Decimal) -> Result<CalendarDate, DateValidationError> {
+        let date = Self::new(y, m, d);
+        date.validate()?;
+        Ok(date)
+    }
+
+    /// Determines if this CalendarDate is a valid date, with the same rules as ``CalendarDate::try_new``.\
+    /// This is useful for dates that were not validated on creation, e.g. deserialized ones.
+    /// 
+    /// **NOTE:** The days dropped by the Gregorian reform are only rejected when the calendar is decided by the reform date.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Checks the month and day of this CalendarDate, returning the first rule it breaks.
+    fn validate(&self) -> Result<(), DateValidationError> {
+        if !(1..=12).contains(&self.m) {
+            return Err(DateValidationError::MonthOutOfRange);
+        }
+
+        let last = month_length(self.m, self.leap_year());
+        if self.d < Decimal::ONE || self.d >= Decimal::from(last) + Decimal::ONE {
+            return Err(DateValidationError::DayOutOfRange);
+        }
+
+        // A date read in the Julian calendar which falls on or after the first Gregorian day never occurred
+        if self.calendar.is_none()
+            && self.get_calendar() == Calendar::Julian
+            && julian_day(self.y, self.m, self.d, Calendar::Julian).day >= self.get_reform().day
+        {
+            return Err(DateValidationError::DroppedDay);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a CalendarDate from a year and the day of the year, the inverse of ``CalendarDate::day_of_the_year``.\
+    /// ``doy`` must be between and including 1 and 365 (or 366 if ``year`` is a leap year).
+    /// 
+    /// Taken from "Day of the Year".
+    /// 
+    /// **NOTE:** 1582 only has 355 days, as October 5th through 14th were dropped by the Gregorian reform.\
+    /// Its days are counted as they occurred, e.g. day 278 is October 15th, as in ``CalendarDate::day_of_the_year``.
+    pub fn from_day_of_year(year: i32, doy: i32) -> Result<CalendarDate, DateValidationError> {
+        let jan1 = CalendarDate::from_ymd(year, 1, 1);
+        let leap_year = jan1.leap_year();
+        let skipped = Self::skipped_days(&jan1, &CalendarDate::from_ymd(year, 12, 31)).to_i32().unwrap();
+        let days = if leap_year { 366 } else { 365 } - skipped;
+        if !(1..=days).contains(&doy) {
+            return Err(DateValidationError::DayOfYearOutOfRange);
+        }
+
+        // The dropped days never occurred, so the date is counted through the JulianDay instead
+        if skipped > 0 {
+            return Ok(jan1.add_days(Decimal::from(doy - 1)));
+        }
+
+        let k = Decimal::from(if leap_year { 1 } else { 2 });
+        let n = Decimal::from(doy);
+
+        let m = if doy < 32 {
+            Decimal::ONE
+        } else {
+            (dec!(9) * (k + n) / dec!(275) + dec!(0.98)).floor()
+        };
+        let d = n - (dec!(275) * m / dec!(9)).floor() + k * ((m + dec!(9)) / dec!(12)).floor() + dec!(30);
+
+        Ok(Self::new(year, m.to_u8().unwrap(), d))
+    }
+
+    /// Creates a CalendarDate from a whole day and a time of day.\
+    /// The time is folded into the fractional part of the day, counting from midnight.
+    /// 
+    /// Does not validate input, refer to ``CalendarDate::new``.
+    pub fn with_time(y: i32, m: u8, d: u8, h: u8, min: u8, s: Decimal) -> Self {
+        let seconds = Decimal::from(h) * dec!(3600) + Decimal::from(min) * dec!(60) + s;
+        Self::new(y, m, Decimal::from(d) + seconds / dec!(86400))
+    }
+
+    /// Parses an ISO 8601 calendar date of the form ``YYYY-MM-DD``.
+    /// 
+    /// An optional time component ``YYYY-MM-DDThh:mm[:ss[.sss]]`` is folded into the fractional day.\
+    /// Negative (astronomical) years are accepted with a leading minus sign, e.g. ``-0123-12-31``.
+    pub fn parse_iso8601(s: &str) -> Result<CalendarDate, ParseError> {
+        let (date, time) = match s.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+
+        let (sign, date) = match date.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, date.strip_prefix('+').unwrap_or(date)),
+        };
+
+        let mut parts = date.split('-');
+        let (y, m, d) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(y), Some(m), Some(d), None) if y.len() >= 4 && m.len() == 2 && d.len() == 2 => (y, m, d),
+            _ => return Err(ParseError::Malformed),
+        };
+
+        let y = sign * parse_digits::<i32>(y)?;
+        let m = parse_digits::<u8>(m)?;
+        let d = parse_digits::<u8>(d)?;
+
+        let fraction = match time {
+            Some(time) => parse_time(time)?,
+            None => Decimal::ZERO,
+        };
+
+        parsed_date(y, m, Decimal::from(d) + fraction)
+    }
+
+    /// Encodes this CalendarDate as a JSON object, e.g. ``{"year":2000,"month":1,"day":1.5}``, without depending on serde.\
+    /// A forced calendar and a reform date are written as additional ``"calendar"`` and ``"reform"`` fields, as with the ``serde`` feature.
+    pub fn to_json(self) -> String {
+        let calendar = match self.calendar {
+            Some(Calendar::Gregorian) => ",\"calendar\":\"Gregorian\"",
+            Some(Calendar::Julian) => ",\"calendar\":\"Julian\"",
+            None => "",
+        };
+        let reform = match self.reform {
+            Some(reform) => format!(",\"reform\":{}", reform.to_json()),
+            None => String::new(),
+        };
+
+        format!("{{\"year\":{},\"month\":{},\"day\":{}{}{}}}", self.y, self.m, self.d, calendar, reform)
+    }
+
+    /// Decodes a CalendarDate from a JSON object, the inverse of ``CalendarDate::to_json``.\
+    /// The fields may appear in any order, and the date is validated like ``CalendarDate::try_new``.
+    pub fn from_json(s: &str) -> Result<CalendarDate, ParseError> {
+        let body = s.trim()
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or(ParseError::InvalidJson)?;
+
+        let (mut y, mut m, mut d, mut calendar, mut reform) = (None, None, None, None, None);
+        for field in body.split(',') {
+            let (key, value) = field.split_once(':').ok_or(ParseError::InvalidJson)?;
+            let value = value.trim();
+
+            match key.trim() {
+                "\"year\"" => y = value.parse::<i32>().ok(),
+                "\"month\"" => m = value.parse::<u8>().ok(),
+                "\"day\"" => d = Some(JulianDay::from_json(value)?.day),
+                "\"calendar\"" => calendar = match value {
+                    "\"Gregorian\"" => Some(Calendar::Gregorian),
+                    "\"Julian\"" => Some(Calendar::Julian),
+                    _ => return Err(ParseError::InvalidJson),
+                },
+                "\"reform\"" => reform = Some(JulianDay::from_json(value)?),
+                _ => return Err(ParseError::InvalidJson),
+            }
+        }
+
+        let (Some(y), Some(m), Some(d)) = (y, m, d) else {
+            return Err(ParseError::InvalidJson);
+        };
+
+        let date = CalendarDate { y, m, d, calendar, reform };
+        date.validate().map_err(|e| match e {
+            DateValidationError::MonthOutOfRange => ParseError::MonthOutOfRange,
+            _ => ParseError::DayOutOfRange,
+        })?;
+
+        Ok(date)
+    }
+
+    /// Formats this CalendarDate according to ``pattern``.
+    /// 
+    /// Supported tokens:
+    /// - ``%Y``: the year, zero-padded to four digits, with a leading minus sign for negative years
+    /// - ``%m``: the month, zero-padded to two digits
+    /// - ``%d``: the integer part of the day, zero-padded to two digits
+    /// - ``%j``: the day of the year, zero-padded to three digits
+    /// - ``%%``: a literal ``%``
+    /// 
+    /// Any other character is copied to the output unchanged.
+    pub fn format(&self, pattern: &str) -> String {
+        let mut out = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => out.push_str(&format_year(self.y)),
+                Some('m') => out.push_str(&format!("{:02}", self.m)),
+                Some('d') => out.push_str(&format!("{:02}", self.d.trunc())),
+                Some('j') => out.push_str(&format!("{:03}", self.day_of_the_year())),
+                Some('%') => out.push('%'),
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                },
+                None => out.push('%'),
+            }
+        }
+
+        out
+    }
+
+    /// Returns the canonical form of this CalendarDate, where the day lies within the month.\
+    /// Overflowing days are carried into the following months, e.g. 2000 January 32.5 becomes 2000 February 1.5.
+    /// 
+    /// **NOTE:** Panics if the date falls before JulianDay 0, as it cannot be converted back into a CalendarDate.
+    pub fn normalize(&self) -> CalendarDate {
+        self.checked_normalize().unwrap()
+    }
+
+    /// Returns the canonical form of this CalendarDate as with ``CalendarDate::normalize``,
+    /// or ``None`` if the date falls before JulianDay 0.
+    pub fn checked_normalize(&self) -> Option<CalendarDate> {
+        from_julian_day(JulianDay::from(self), self.calendar, self.reform).ok()
+    }
+
+    /// Returns the CalendarDate ``n`` days after this one (or before, if ``n`` is negative).
+    /// 
+    /// **NOTE:** Panics if the result falls before JulianDay 0, as it cannot be converted back into a CalendarDate.
+    pub fn add_days(&self, n: Decimal) -> CalendarDate {
+        from_julian_day(JulianDay::from(self) + n, self.calendar, self.reform).unwrap()
+    }
+
+    /// Returns the CalendarDate ``n`` days after this one (or before, if ``n`` is negative),
+    /// or ``None`` if the result falls before JulianDay 0 or its year does not fit in an ``i32``.
+    pub fn checked_add_days(&self, n: Decimal) -> Option<CalendarDate> {
+        let jd = JulianDay::from(self).day.checked_add(n)?;
+        if jd > MAX_JULIAN_DAY {
+            return None;
+        }
+
+        from_julian_day(JulianDay::new(jd), self.calendar, self.reform).ok()
+    }
+
+    /// Returns the CalendarDate one day after this one, e.g. 1582 October 4 is followed by 1582 October 15.
+    /// 
+    /// **NOTE:** Equivalent to ``add_days(1)``.
+    pub fn succ(&self) -> CalendarDate {
+        self.add_days(Decimal::ONE)
+    }
+
+    /// Returns the CalendarDate one day before this one, e.g. 1582 October 15 is preceded by 1582 October 4.
+    /// 
+    /// **NOTE:** Equivalent to ``add_days(-1)``, so it panics if the result falls before JulianDay 0.
+    pub fn pred(&self) -> CalendarDate {
+        self.add_days(Decimal::NEGATIVE_ONE)
+    }
+
+    /// Returns the first day of the month of this CalendarDate, at midnight.
+    pub fn floor_to_month(&self) -> CalendarDate {
+        CalendarDate { d: Decimal::ONE, ..*self }
+    }
+
+    /// Returns the first midnight falling on ``week_start`` at or after this CalendarDate,
+    /// e.g. with weeks starting on Monday, Tuesday 2024 January 30 is rounded up to Monday 2024 February 5.
+    ///
+    /// **NOTE:** A date already at midnight on ``week_start`` is returned unchanged, while a later time on that day rounds up to the following week.\
+    /// The days are counted through the JulianDay, so the days dropped in 1582 October are skipped.
+    pub fn ceil_to_week(&self, week_start: WeekDay) -> CalendarDate {
+        let midnight = CalendarDate { d: self.d.floor(), ..*self };
+        let ahead = midnight.days_until(week_start);
+        let ahead = if ahead == 0 && !self.d.fract().is_zero() { 7 } else { ahead };
+
+        midnight.add_days(Decimal::from(ahead))
+    }
+
+    /// Returns the CalendarDate ``n`` months after this one (or before, if ``n`` is negative).
+    /// 
+    /// If the day does not exist in the target month, it is clamped to the last day of that month,\
+    /// e.g. ``2001-01-31`` plus one month is ``2001-02-28``. The fractional part of the day is kept.
+    /// 
+    /// **NOTE:** A result landing on one of the days dropped in 1582 October is interpreted in the Julian calendar,
+    /// i.e. 1582 October 10th becomes 1582 October 20th.\
+    /// Panics if the resulting year does not fit in an ``i32``, refer to ``CalendarDate::checked_add_months``.
+    pub fn add_months(&self, n: i32) -> CalendarDate {
+        self.checked_add_months(n).expect("year does not fit in an i32")
+    }
+
+    /// Returns the CalendarDate ``n`` months after this one (or before, if ``n`` is negative),
+    /// or ``None`` if the resulting year does not fit in an ``i32``. Follows the same rules as ``CalendarDate::add_months``.
+    pub fn checked_add_months(&self, n: i32) -> Option<CalendarDate> {
+        let months = i64::from(self.y) * 12 + i64::from(self.m) - 1 + i64::from(n);
+        let y = i32::try_from(months.div_euclid(12)).ok()?;
+        let m = (months.rem_euclid(12) + 1) as u8;
+
+        let target = CalendarDate { y, m, d: Decimal::ONE, ..*self };
+        let last = Decimal::from(month_length(m, target.leap_year()));
+        let d = if self.d.trunc() > last {
+            last + self.d.fract()
+        } else {
+            self.d
+        };
+
+        let date = CalendarDate { y, m, d, ..*self };
+        match date.validate() {
+            Err(DateValidationError::DroppedDay) => Some(date.normalize()),
+            _ => Some(date),
+        }
+    }
+
+    /// Returns a lazy iterator over every day from ``start`` (inclusive) up to ``end`` (exclusive).
+    /// 
+    /// The iterator steps through consecutive JulianDays, so the days dropped in 1582 October are skipped.\
+    /// The dates follow the calendar and reform date of ``start``, see ``CalendarDate::new_in`` and ``CalendarDate::with_reform``.
+    /// 
+    /// **NOTE:** Dates before JulianDay 0 cannot be converted back from a JulianDay, refer to ``CalendarDate::try_from``.\
+    /// A range starting earlier thus starts on its first day on or after JulianDay 0, keeping the time of day of ``start``.
+    pub fn range(start: CalendarDate, end: CalendarDate) -> DateIter {
+        let first = JulianDay::from(start);
+        let first = if first.day < Decimal::ZERO { first + (-first.day).ceil() } else { first };
+
+        DateIter {
+            next: first,
+            end: JulianDay::from(end),
+            step: Decimal::ONE,
+            calendar: start.calendar,
+            reform: start.reform,
+        }
+    }
+
+    /// Returns the number of days in the given month, according to the calendar in use at the time.
+    /// 
+    /// **NOTE:** 1582 October only has 21 days, as the 5th through 14th were dropped by the Gregorian reform.
+    pub fn days_in_month(year: i32, month: u8) -> u8 {
+        if year == 1582 && month == 10 {
+            return 21;
+        }
+
+        month_length(month, CalendarDate::new(year, month, Decimal::ONE).leap_year())
+    }
+
+    /// Returns a lazy iterator over every day of the given month at 0h, along with its day of the week.
+    /// 
+    /// **NOTE:** 1582 October skips from the 4th to the 15th, see ``CalendarDate::days_in_month``.
+    pub fn month_days(year: i32, month: u8) -> impl Iterator<Item = (CalendarDate, WeekDay)> {
+        let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+
+        CalendarDate::range(CalendarDate::new(year, month, Decimal::ONE), CalendarDate::new(next_year, next_month, Decimal::ONE))
+            .map(|date| (date, date.day_of_the_week()))
+    }
+
+    /// Returns how many times the given day of the week occurs in the given year, either 52 or 53.
+    /// 
+    /// **NOTE:** 1582 only has 355 days, so each day of the week occurs 50 or 51 times that year.
+    pub fn count_weekday(year: i32, day: WeekDay) -> u32 {
+        CalendarDate::range(CalendarDate::from_ymd(year, 1, 1), CalendarDate::from_ymd(year + 1, 1, 1))
+            .filter(|date| date.day_of_the_week() == day)
+            .count() as u32
+    }
+
+    /// Returns the last day of the month this CalendarDate falls in, at 0h.
+    pub fn last_day_of_month(&self) -> CalendarDate {
+        let d = month_length(self.m, self.leap_year());
+        CalendarDate { d: Decimal::from(d), ..*self }
+    }
+
+    /// Returns the JulianDay at 0h of this CalendarDate, discarding the time of day.\
+    /// This is the ``JD0`` many formulas, such as sidereal time or rise and set times, start from.
+    /// 
+    /// **NOTE:** As the JulianDay begins at noon, the result always ends in ``.5``.
+    pub fn julian_day_at_midnight(&self) -> JulianDay {
+        JulianDay::from(CalendarDate { d: self.d.floor(), ..*self })
+    }
+
+    /// Returns the JulianDay of this CalendarDate read as a local civil date, ``hours_east`` hours ahead of UTC.\
+    /// The offset is subtracted from the fractional day, e.g. a date at UTC+2 is 2/24 of a day earlier than the same date in UTC.
+    pub fn to_julian_day_with_offset(self, hours_east: Decimal) -> JulianDay {
+        JulianDay::from(self) - hours_east / dec!(24)
+    }
+
+    /// Returns the Rata Die of this CalendarDate, the number of the day counting 0001 January 1st as day 1.\
+    /// The year, month and day are always read in the proleptic Gregorian calendar, ignoring the fractional part of the day.
+    /// 
+    /// **NOTE:** Unlike the JulianDay, this does not switch to the Julian calendar before 1582 October 15th,
+    /// so e.g. 1582 October 4th is Rata Die 577725, ten days earlier than the day that historically preceded October 15th.
+    pub fn to_rata_die(self) -> i64 {
+        let jd = julian_day(self.y, self.m, self.d.floor(), Calendar::Gregorian);
+        (jd.day - RATA_DIE_EPOCH).to_i64().unwrap()
+    }
+
+    /// Creates a CalendarDate in the proleptic Gregorian calendar from its Rata Die, the inverse of ``CalendarDate::to_rata_die``.
+    /// 
+    /// **NOTE:** Panics if the date falls before JulianDay 0, i.e. for a Rata Die below -1721424.
+    pub fn from_rata_die(rd: i64) -> CalendarDate {
+        CalendarDate::checked_from_rata_die(rd).unwrap()
+    }
+
+    /// Creates a CalendarDate from its Rata Die as with ``CalendarDate::from_rata_die``,
+    /// or ``None`` if the result falls before JulianDay 0 or its year does not fit in an ``i32``.
+    pub fn checked_from_rata_die(rd: i64) -> Option<CalendarDate> {
+        let jd = Decimal::from(rd) + RATA_DIE_EPOCH;
+        if jd > MAX_JULIAN_DAY {
+            return None;
+        }
+
+        from_julian_day(JulianDay::new(jd), Some(Calendar::Gregorian), None).ok()
+    }
+
+    /// Determines if two CalendarDate objects fall on the same day, regardless of the time of day.
+    /// 
+    /// **NOTE:** Days are compared by their JulianDay at 0h, so the same day expressed in two different calendars is the same.
+    pub fn same_day(&self, other: &CalendarDate) -> bool {
+        self.julian_day_at_midnight() == other.julian_day_at_midnight()
+    }
+
+    /// Orders two CalendarDate objects by their day alone, so that two dates on the same day are equal regardless of the time of day.\
+    /// This is the ordering counterpart of ``CalendarDate::same_day``, e.g. to sort a schedule by day while keeping events of a day in order.
+    pub fn cmp_day_only(&self, other: &CalendarDate) -> Ordering {
+        self.julian_day_at_midnight().day.cmp(&other.julian_day_at_midnight().day)
+    }
+
+    /// Returns the time of day as ``(hours, minutes, seconds)``, taken from the fractional part of the day.
+    /// 
+    /// **NOTE:** Calendar days begin at midnight, so a fraction of ``0.5`` is 12h00m00s.\
+    /// This differs from the JulianDay, which begins at noon.
+    pub fn time_of_day(&self) -> (u8, u8, Decimal) {
+        let seconds = self.d.fract() * dec!(86400);
+
+        let h = (seconds / dec!(3600)).floor();
+        let min = ((seconds - h * dec!(3600)) / dec!(60)).floor();
+        let s = seconds - h * dec!(3600) - min * dec!(60);
+
+        (h.to_u8().unwrap(), min.to_u8().unwrap(), s)
+    }
+
+    /// Returns the year of this CalendarDate.
+    pub fn year(&self) -> i32 {
+        self.y
+    }
+
+    /// Returns the month of this CalendarDate, between and including 1 and 12.
+    pub fn month(&self) -> u8 {
+        self.m
+    }
+
+    /// Returns the day of this CalendarDate, including any fractional part.
+    pub fn day(&self) -> Decimal {
+        self.d
+    }
+
+    /// Determines if this calendar date falls on a leap year.
+    /// 
+    /// **NOTE**: The way a leap year is calculated depends on the calendar in use at the time.
+    /// - If ``CalendarDate`` occurs strictly before 1582 October 15th, leap years will be calculated according to the Julian Calendar
+    /// - If ``CalendarDate`` occurs during or after 1582 October 15th, leap years will be calculated according to the Gregorian Calendar
+    pub fn leap_year(&self) -> bool {
+        match self.get_calendar() {
+            Calendar::Gregorian => {
+                (self.y % 4 == 0) && ((self.y % 100 != 0) || (self.y % 400 == 0))
+            },
+            Calendar::Julian => {
+                self.y % 4 == 0
+            }
+        }
+    }
+
+    /// Returns the days between two CalendarDate objects.\
+    /// Defined as: ``|lhs - rhs|``.
+    pub fn days_between(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
+        Self::difference(lhs, rhs).abs()
+    }
+
+    /// Returns the weeks between two CalendarDate objects, including any fractional part.\
+    /// Defined as: ``|lhs - rhs| / 7``.
+    pub fn weeks_between(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
+        Self::days_between(lhs, rhs) / dec!(7)
+    }
+
+    /// Returns the number of whole weeks between two CalendarDate objects, discarding any remaining days.
+    pub fn whole_weeks_between(lhs: &CalendarDate, rhs: &CalendarDate) -> i64 {
+        Self::weeks_between(lhs, rhs).trunc().to_i64().unwrap()
+    }
+
+    /// Returns the number of calendar dates skipped by the Gregorian reform between two CalendarDate objects.\
+    /// This is 10 (1582 October 5th through 14th) when the interval spans the reform, and 0 otherwise.\
+    /// The reform date of the earlier date is used, e.g. 11 days are skipped in 1752 September under ``JulianDay::BRITISH_REFORM``.
+    /// 
+    /// **NOTE:** ``days_between`` counts elapsed days, which already excludes the skipped dates.\
+    /// The number of calendar dates crossed is thus ``days_between + skipped_days``.
+    pub fn skipped_days(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
+        let (start, end) = if lhs <= rhs { (lhs, rhs) } else { (rhs, lhs) };
+
+        let reform = start.get_reform().day;
+        if JulianDay::from(start).day < reform && JulianDay::from(end).day >= reform {
+            let (y, m, d) = start.reform_date();
+            julian_day(y, m, d, Calendar::Julian).day - reform
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    /// Returns the sum of the gaps between consecutive CalendarDate objects.\
+    /// Defined as: ``(dates[1] - dates[0]) + (dates[2] - dates[1]) + ...``, or 0 for fewer than two dates.
+    pub fn total_days(dates: &[CalendarDate]) -> Decimal {
+        dates.windows(2)
+            .map(|w| Self::difference(&w[1], &w[0]))
+            .sum()
+    }
+
+    /// Returns the number of complete years elapsed since ``earlier``, e.g. someone's age given their date of birth.\
+    /// The count is decremented if this year's anniversary has not yet been reached.
+    /// 
+    /// **NOTE:** An anniversary on February 29th falls on February 28th in common years.
+    pub fn complete_years_since(&self, earlier: &CalendarDate) -> i32 {
+        let anniversary_day = match (earlier.m, earlier.d.floor()) {
+            (2, d) if d == dec!(29) && Self::days_in_month(self.y, 2) == 28 => dec!(28),
+            (_, d) => d,
+        };
+
+        let years = self.y - earlier.y;
+        if (self.m, self.d.floor()) < (earlier.m, anniversary_day) {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// Returns the day of the week corresponding to this CalendarDate.\
+    /// The fractional part of the day is ignored, i.e. the day of the week is that of the civil day at 0h,
+    /// so a late-evening instant such as October 4.99 still falls on October 4th.
+    /// 
+    /// **NOTE:** The week was not modified in any way by the Gregorian reform of the Julian calendar.\
+    /// Thus, in 1582, ``Thursday October 4`` was followed by ``Friday October 15``.
+    pub fn day_of_the_week(&self) -> WeekDay {
+        let date_0hr = CalendarDate {d: self.d.floor(), ..*self};
+        JulianDay::from(date_0hr).weekday()
+    }
+
+    /// Returns the number of days from this CalendarDate until the next ``target`` day of the week, between and including 0 and 6.\
+    /// Returns 0 if this CalendarDate already falls on ``target``.
+    pub fn days_until(&self, target: WeekDay) -> u8 {
+        (target as u8 + 7 - self.day_of_the_week() as u8) % 7
+    }
+
+    /// Returns the quarter of the year this CalendarDate falls in, between and including 1 and 4.
+    pub fn quarter(&self) -> u8 {
+        (self.m - 1) / 3 + 1
+    }
+
+    /// Determines if this CalendarDate falls on a Saturday or a Sunday.
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.day_of_the_week(), WeekDay::Saturday | WeekDay::Sunday)
+    }
+
+    /// Returns an integer between and including 1 and 365 (or 366 if date corresponds to a leap year).\
+    /// This represents the current day as an offset of the current year.
+    /// 
+    /// **NOTE:** The days dropped by the Gregorian reform are not counted, e.g. 1582 October 15th is day 278.
+    pub fn day_of_the_year(&self) -> i32 {
+        let k = Decimal::from(match self.leap_year() {
+            true => 1,
+            false => 2,
+        });
+
+        let m_d = Decimal::from(self.m);
+        let d_d = self.d;
+
+        let mut n = ((dec!(275) * m_d) / dec!(9)).floor() - k * ((m_d + dec!(9)) / dec!(12)).floor() + d_d - dec!(30);
+
+        // A proleptic calendar never skips any days
+        if self.calendar.is_none() {
+            let jan1 = CalendarDate {m: 1, d: Decimal::ONE, ..*self};
+            n -= Self::skipped_days(&jan1, self);
+        }
+
+        n.to_i32().unwrap()
+    }
+
+    /// Returns the ISO 8601 ``(week-year, week number)`` of this CalendarDate.
+    /// 
+    /// ISO weeks start on Monday, and week 1 is the week containing the year's first Thursday.\
+    /// **NOTE:** Early January dates may belong to the last week of the previous week-year,
+    /// and late December dates to week 1 of the next week-year.
+    pub fn iso_week(&self) -> (i32, u8) {
+        let weekday = i32::from(self.day_of_the_week().number());
+        let week = (self.day_of_the_year() - weekday + 10) / 7;
+
+        if week < 1 {
+            (self.y - 1, iso_weeks_in_year(self.y - 1))
+        } else if week > i32::from(iso_weeks_in_year(self.y)) {
+            (self.y + 1, 1)
+        } else {
+            (self.y, week as u8)
+        }
+    }
+
+    /// Returns the difference between two CalendarDate objects.\
+    /// Defined as: ``lhs - rhs``
+    pub fn difference(lhs: &CalendarDate, rhs: &CalendarDate) -> Decimal {
+        let lhs_jd = JulianDay::from(lhs);
+        let rhs_jd = JulianDay::from(rhs);
+        lhs_jd.day - rhs_jd.day
+    }
+
+    /// Determines what calendar system the current CalendarDate falls under.\
+    /// Unless forced with ``CalendarDate::new_in`` or ``CalendarDate::with_calendar``, this is decided by the reform date,
+    /// 1582 October 15th unless set with ``CalendarDate::with_reform``.
+    pub fn get_calendar(&self) -> Calendar {
+        if let Some(calendar) = self.calendar {
+            return calendar;
+        }
+
+        let (reform_y, reform_m, reform_d) = self.reform_date();
+        let year_is_julian = self.y < reform_y;
+        let month_is_julian = self.y == reform_y && self.m < reform_m;
+        let day_is_julian = self.y == reform_y && self.m == reform_m && self.d < reform_d;
+
+        if year_is_julian || month_is_julian || day_is_julian {
+            Calendar::Julian
+        } else {
+            Calendar::Gregorian
+        }
+    }
+
+    /// Returns the first day of the Gregorian calendar for this CalendarDate as ``(y, m, d)``, in the Gregorian calendar.
+    fn reform_date(&self) -> (i32, u8, Decimal) {
+        match self.reform {
+            None => (1582, 10, dec!(15)),
+            Some(reform) => {
+                let date = from_julian_day(reform, Some(Calendar::Gregorian), None).unwrap();
+                (date.y, date.m, date.d)
+            },
+        }
+    }
+}
+
+impl PartialEq for CalendarDate {
+    /// Two CalendarDate objects are equal when they correspond to the same JulianDay.
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CalendarDate {}
+
+impl Hash for CalendarDate {
+    /// The hash is derived from the canonical JulianDay of this CalendarDate,
+    /// so that two dates which compare equal also hash equal.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        JulianDay::from(self).day.normalize().hash(state);
+    }
+}
+
+impl PartialOrd for CalendarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CalendarDate {
+    /// Orders CalendarDate objects chronologically by comparing their JulianDay.
+    /// 
+    /// **NOTE:** Comparing ``(y, m, d)`` directly would be wrong across the Julian/Gregorian boundary.\
+    /// This also provides ``min``, ``max`` and ``clamp``, e.g. to bound a date to an observation window.
+    fn cmp(&self, other: &Self) -> Ordering {
+        JulianDay::from(self).day.cmp(&JulianDay::from(other).day)
+    }
+}
+
+impl PartialEq<CalendarDate> for JulianDay {
+    /// A JulianDay is equal to a CalendarDate when it is the JulianDay of that date, including its fractional day.
+    /// 
+    /// **NOTE:** Julian Days begin at noon while calendar days begin at midnight,
+    /// so a whole-day CalendarDate such as 1957 October 4.0 is equal to JulianDay 2436115.5.\
+    /// References can be compared as well, e.g. ``&jd == &date``.
+    fn eq(&self, other: &CalendarDate) -> bool {
+        self.day == JulianDay::from(other).day
+    }
+}
+
+impl PartialEq<JulianDay> for CalendarDate {
+    /// Equivalent to comparing the JulianDay with this CalendarDate.
+    fn eq(&self, other: &JulianDay) -> bool {
+        other == self
+    }
+}
+
+/// Formats a year as at least four digits, with a leading minus sign for negative years.
+fn format_year(y: i32) -> String {
+    if y < 0 {
+        format!("-{:04}", y.unsigned_abs())
+    } else {
+        format!("{:04}", y)
+    }
+}
+
+/// Returns the number of ISO 8601 weeks in the given week-year, either 52 or 53.
+fn iso_weeks_in_year(y: i32) -> u8 {
+    let jan_1 = CalendarDate::new(y, 1, dec!(1));
+
+    match jan_1.day_of_the_week() {
+        WeekDay::Thursday => 53,
+        WeekDay::Wednesday if jan_1.leap_year() => 53,
+        _ => 52,
+    }
+}
+
+/// Returns the number of the last day of the given month.
+fn month_length(m: u8, leap_year: bool) -> u8 {
+    match m {
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Creates a validated CalendarDate from parsed fields, reporting an invalid date as a ParseError.
+fn parsed_date(y: i32, m: u8, d: Decimal) -> Result<CalendarDate, ParseError> {
+    CalendarDate::try_new(y, m, d).map_err(|e| match e {
+        DateValidationError::MonthOutOfRange => ParseError::MonthOutOfRange,
+        _ => ParseError::DayOutOfRange,
+    })
+}
+
+/// Parses a string made up exclusively of ASCII digits.
+fn parse_digits<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::Malformed);
+    }
+
+    s.parse().map_err(|_| ParseError::Malformed)
+}
+
+/// Parses a time of the form ``hh:mm[:ss[.sss]]`` into a fraction of a day.
+fn parse_time(s: &str) -> Result<Decimal, ParseError> {
+    let mut parts = s.split(':');
+    let (h, m, sec) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(h), Some(m), sec, None) if h.len() == 2 && m.len() == 2 => (h, m, sec),
+        _ => return Err(ParseError::Malformed),
+    };
+
+    let h = parse_digits::<u8>(h)?;
+    let m = parse_digits::<u8>(m)?;
+
+    let sec = match sec {
+        Some(sec) => {
+            let (whole, frac) = sec.split_once('.').unwrap_or((sec, "0"));
+            if whole.len() != 2 {
+                return Err(ParseError::Malformed);
+            }
+            parse_digits::<u8>(whole)?;
+            parse_digits::<u64>(frac)?;
+            Decimal::from_str(sec).map_err(|_| ParseError::Malformed)?
+        },
+        None => Decimal::ZERO,
+    };
+
+    if h > 23 || m > 59 || sec >= dec!(60) {
+        return Err(ParseError::TimeOutOfRange);
+    }
+
+    let seconds = Decimal::from(h) * dec!(3600) + Decimal::from(m) * dec!(60) + sec;
+    Ok(seconds / dec!(86400))
+}
+
+impl TryFrom<&str> for CalendarDate {
+    type Error = ParseError;
+
+    /// Parses a date in any of the following formats, tried in order:
+    /// - ``YYYY-MM-DD``, with the optional time and sign accepted by ``CalendarDate::parse_iso8601``
+    /// - ``YYYY/MM/DD``
+    /// - ``DD-MM-YYYY``
+    /// - ``YYYY-DDD``, where ``DDD`` is the day of the year
+    /// 
+    /// Fails with ``ParseError::UnrecognizedFormat`` if the string matches none of them,
+    /// or with a range error if it matches one but the date does not exist.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match CalendarDate::parse_iso8601(s) {
+            Err(ParseError::Malformed) => {},
+            result => return result,
+        }
+
+        let mut parts = s.split('/');
+        if let (Some(y), Some(m), Some(d), None) = (parts.next(), parts.next(), parts.next(), parts.next())
+            && y.len() >= 4 && m.len() == 2 && d.len() == 2
+        {
+            return parsed_date(parse_digits(y)?, parse_digits(m)?, Decimal::from(parse_digits::<u8>(d)?));
+        }
+
+        let mut parts = s.split('-');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(d), Some(m), Some(y), None) if d.len() == 2 && m.len() == 2 && y.len() >= 4 => {
+                parsed_date(parse_digits(y)?, parse_digits(m)?, Decimal::from(parse_digits::<u8>(d)?))
+            },
+            (Some(y), Some(doy), None, _) if y.len() >= 4 && doy.len() == 3 => {
+                CalendarDate::from_day_of_year(parse_digits(y)?, parse_digits(doy)?).map_err(|_| ParseError::DayOutOfRange)
+            },
+            _ => Err(ParseError::UnrecognizedFormat),
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    /// Renders this CalendarDate as ``YYYY-MM-DD``, using the integer part of the day.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format("%Y-%m-%d"))
+    }
+}
+
+impl Sub for CalendarDate {
+    type Output = DaySpan;
+
+    /// Returns the signed span of days between two CalendarDate objects.\
+    /// Equivalent to ``CalendarDate::difference(&self, &rhs)``.
+    fn sub(self, rhs: CalendarDate) -> Self::Output {
+        DaySpan(CalendarDate::difference(&self, &rhs))
+    }
+}
+
+impl Sub for &CalendarDate {
+    type Output = DaySpan;
+
+    /// Returns the signed span of days between two &CalendarDate objects.
+    fn sub(self, rhs: &CalendarDate) -> Self::Output {
+        DaySpan(CalendarDate::difference(self, rhs))
+    }
+}
+
+impl Add<DaySpan> for CalendarDate {
+    type Output = CalendarDate;
+
+    /// Equivalent to ``CalendarDate::add_days``.
+    fn add(self, span: DaySpan) -> Self::Output {
+        self.add_days(span.0)
+    }
+}
+
+impl Sub<DaySpan> for CalendarDate {
+    type Output = CalendarDate;
+
+    /// Equivalent to ``CalendarDate::add_days`` with a negated span.
+    fn sub(self, span: DaySpan) -> Self::Output {
+        self.add_days(-span.0)
+    }
+}
+
+/// A signed span of (possibly fractional) days, e.g. the result of subtracting two CalendarDate objects.
+///
+/// **NOTE:** Spans count elapsed days, so the dates dropped by the Gregorian reform are not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct DaySpan(#[cfg_attr(feature = "serde", serde(with = "rust_decimal::serde::float"))] pub Decimal);
+
+impl DaySpan {
+    /// Returns the span in days, including any fractional part.
+    pub fn as_days(&self) -> Decimal {
+        self.0
+    }
+
+    /// Returns the span in weeks of 7 days, including any fractional part.
+    pub fn as_weeks(&self) -> Decimal {
+        self.0 / dec!(7)
+    }
+
+    /// Returns the number of whole days in the span, truncated towards zero.
+    pub fn whole_days(&self) -> i64 {
+        self.0.trunc().to_i64().unwrap()
+    }
+
+    /// Describes the span in words, reading it as ``lhs - rhs``: e.g. ``3 days before`` when ``lhs`` falls 3 days before ``rhs``,
+    /// ``1 day after`` when it falls 1 day after, or ``same day`` for an empty span.
+    /// 
+    /// **NOTE:** Only an empty span is described as ``same day``, a span of a fraction of a day gives e.g. ``0.25 days after``.
+    pub fn describe(&self) -> String {
+        if self.0.is_zero() {
+            return String::from("same day");
+        }
+
+        let days = self.0.abs().normalize();
+        let unit = if days == Decimal::ONE { "day" } else { "days" };
+        let direction = if self.0.is_sign_negative() { "before" } else { "after" };
+
+        format!("{} {} {}", days, unit, direction)
+    }
+}
+
+impl Neg for DaySpan {
+    type Output = DaySpan;
+
+    /// Reverses the direction of the span, i.e. ``-(lhs - rhs) == rhs - lhs``.
+    fn neg(self) -> Self::Output {
+        DaySpan(-self.0)
+    }
+}
+
+impl Add for DaySpan {
+    type Output = DaySpan;
+
+    fn add(self, rhs: DaySpan) -> Self::Output {
+        DaySpan(self.0 + rhs.0)
+    }
+}
+
+impl Sub for DaySpan {
+    type Output = DaySpan;
+
+    fn sub(self, rhs: DaySpan) -> Self::Output {
+        DaySpan(self.0 - rhs.0)
+    }
+}
+
+impl fmt::Display for DaySpan {
+    /// Renders the span as a number of days, e.g. ``7.5 days``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" days")
+    }
+}
+
+/// Builds a CalendarDate from named fields, created by ``CalendarDate::builder``.
+///
+/// ``build`` runs the same validation as ``CalendarDate::try_new``.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CalendarDateBuilder {
+    y: Option<i32>,
+    m: Option<u8>,
+    d: Option<Decimal>,
+}
+
+impl CalendarDateBuilder {
+    pub fn year(self, y: i32) -> Self {
+        Self { y: Some(y), ..self }
+    }
+
+    pub fn month(self, m: u8) -> Self {
+        Self { m: Some(m), ..self }
+    }
+
+    pub fn day(self, d: Decimal) -> Self {
+        Self { d: Some(d), ..self }
+    }
+
+    pub fn build(self) -> Result<CalendarDate, DateValidationError> {
+        match (self.y, self.m, self.d) {
+            (Some(y), Some(m), Some(d)) => CalendarDate::try_new(y, m, d),
+            _ => Err(DateValidationError::MissingField),
+        }
+    }
+}
+
+/// An iterator over CalendarDate objects, created by ``CalendarDate::range`` or from a ``DateRange``.
+#[derive(Debug, Clone)]
+pub struct DateIter {
+    next: JulianDay,
+    end: JulianDay,
+    step: Decimal,
+    calendar: Option<Calendar>,
+    reform: Option<JulianDay>,
+}
+
+impl DateIter {
+    /// Yields every ``n``th day instead of every day. ``n`` may be fractional, but must be positive.
+    pub fn step_by_days(self, n: Decimal) -> DateIter {
+        assert!(n > Decimal::ZERO, "step must be positive");
+        DateIter { step: n, ..self }
+    }
+}
+
+impl DateIter {
+    /// Returns the number of dates left to yield.\
+    /// Every one of them can be converted, as ``CalendarDate::range`` starts on or after JulianDay 0.
+    fn remaining(&self) -> usize {
+        if self.next.day >= self.end.day {
+            return 0;
+        }
+
+        ((self.end - self.next) / self.step).ceil().to_usize().unwrap_or(usize::MAX)
+    }
+}
+
+impl Iterator for DateIter {
+    type Item = CalendarDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.day >= self.end.day {
+            return None;
+        }
+
+        let date = from_julian_day(self.next, self.calendar, self.reform).ok()?;
+        self.next = self.next + self.step;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for DateIter {
+    /// Yields the dates from the end, keeping the same steps from the start of the range.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining();
+        if remaining == 0 {
+            return None;
+        }
+
+        let last = self.next + self.step * Decimal::from(remaining - 1);
+        self.end = last;
+        from_julian_day(last, self.calendar, self.reform).ok()
+    }
+}
+
+impl ExactSizeIterator for DateIter {}
+
+/// The days from ``start`` (inclusive) up to ``end`` (exclusive), which can be iterated in either direction.
+/// 
+/// **NOTE:** As with ``CalendarDate::range``, the days dropped in 1582 October are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: CalendarDate,
+    pub end: CalendarDate,
+}
+
+impl DateRange {
+    pub fn new(start: CalendarDate, end: CalendarDate) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the number of days in this DateRange, or 0 if ``end`` is not after ``start``.
+    pub fn len(&self) -> usize {
+        self.into_iter().len()
+    }
+
+    /// Determines if this DateRange contains no days.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl IntoIterator for DateRange {
+    type Item = CalendarDate;
+    type IntoIter = DateIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CalendarDate::range(self.start, self.end)
+    }
+}
+
+impl TryFrom<JulianDay> for CalendarDate {
+    type Error = CalendarDateError;
+
+    /// Taken from "Calculation of the Calendar Date from the JD"
+    /// 
+    /// **NOTE:** A valid julian day does not neccessarily correspond to a valid calendar date
+    /// We require jd >= 0 for the conversion to be successful
+    fn try_from(j: JulianDay) -> Result<Self, Self::Error> {
+        from_julian_day(j, None, None)
+    }
+}
+
+/// Converts a JulianDay into a CalendarDate in the given calendar,
+/// or according to the ``reform`` date (1582 October 15th if ``None``) if ``calendar`` is ``None``.
+fn from_julian_day(j: JulianDay, calendar: Option<Calendar>, reform: Option<JulianDay>) -> Result<CalendarDate, CalendarDateError> {
+    if j.day < Decimal::ZERO {
+        return Err(CalendarDateError::InvalidJulianDay)
+    }
+
+    let jd = j.day + dec!(0.5);
+    
+    let z = jd.floor();
+    let f = jd - z;
+
+    let gregorian = match calendar {
+        Some(calendar) => calendar == Calendar::Gregorian,
+        None => z >= (reform.unwrap_or(JulianDay::GREGORIAN_REFORM).day + dec!(0.5)).floor(),
+    };
+
+    let a = if gregorian {
+        let alpha = ((z - dec!(1867216.25)) / dec!(36524.25)).floor();
+        z + dec!(1.0) + alpha - (alpha / dec!(4.0)).floor()
+    } else {
+        z
+    };
+
+    let b = a + dec!(1524.0);
+    let c = ((b - dec!(122.1)) / dec!(365.25)).floor();
+    let d = (dec!(365.25) * c).floor();
+    let e = ((b - d) / dec!(30.6001)).floor();
+
+    let day = b - d - (dec!(30.6001) * e).floor() + f;
+
+    let month = if e < dec!(14.0) {
+        e - dec!(1.0)
+    } else {
+        e - dec!(13.0)
+    };
+
+    let year = if month > dec!(2.0) {
+        c - dec!(4716.0)
+    } else {
+        c - dec!(4715.0)
+    };
+
+    let year = year.trunc().to_i32().ok_or(CalendarDateError::UnrepresentableDate)?;
+    let date = CalendarDate::new(year, month.trunc().to_u8().unwrap(), day);
+    Ok(CalendarDate { calendar, reform, ..date })
+}
+
+/// Converts a JulianDay before the Gregorian reform into a CalendarDate, including JulianDays below 0.\
+/// The Julian calendar repeats every 4 years (1461 days), so earlier JulianDays are shifted forward by whole cycles.
+///
+/// **NOTE:** Panics if the JulianDay falls on or after the Gregorian reform.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn julian_calendar_date(j: JulianDay) -> CalendarDate {
+    assert!(j.day < JulianDay::GREGORIAN_REFORM.day);
+
+    let cycles = if j.day < Decimal::ZERO { (-j.day / dec!(1461)).ceil() } else { Decimal::ZERO };
+    let date = from_julian_day(JulianDay::new(j.day + cycles * dec!(1461)), None, None).unwrap();
+    CalendarDate { y: date.y - 4 * cycles.to_i32().unwrap(), ..date }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::julian::*;
+
+    #[test]
+    fn test_week_day_next_previous() {
+        assert_eq!(WeekDay::Monday.next(), WeekDay::Tuesday);
+        assert_eq!(WeekDay::Saturday.next(), WeekDay::Sunday);
+        assert_eq!(WeekDay::Sunday.previous(), WeekDay::Saturday);
+        assert_eq!(WeekDay::Tuesday.previous(), WeekDay::Monday);
+
+        let mut day = WeekDay::Wednesday;
+        for _ in 0..7 {
+            assert_eq!(day.next().previous(), day);
+            day = day.next();
+        }
+        assert_eq!(day, WeekDay::Wednesday);
+    }
+
+    #[test]
+    fn test_week_starting() {
+        use WeekDay::*;
+
+        assert_eq!(WeekDay::week_starting(Sunday), [Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday]);
+        assert_eq!(WeekDay::week_starting(Monday), [Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday]);
+        assert_eq!(WeekDay::week_starting(Saturday), [Saturday, Sunday, Monday, Tuesday, Wednesday, Thursday, Friday]);
+    }
+
+    #[test]
+    fn test_week_day_number() {
+        assert_eq!(WeekDay::Monday.number(), 1);
+        assert_eq!(WeekDay::Wednesday.number(), 3);
+        assert_eq!(WeekDay::Saturday.number(), 6);
+        assert_eq!(WeekDay::Sunday.number(), 7);
+    }
+
+    #[test]
+    fn test_week_day_try_from_u8() {
+        assert_eq!(WeekDay::try_from(0u8).unwrap(), WeekDay::Sunday);
+        assert_eq!(WeekDay::try_from(6u8).unwrap(), WeekDay::Saturday);
+        assert!(matches!(WeekDay::try_from(7u8), Err(WeekDayError::InvalidDayNumber)));
+
+        for d in 0..7u8 {
+            assert_eq!(WeekDay::try_from(d).unwrap(), WeekDay::try_from(Decimal::from(d)).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_week_day_from_str() {
+        assert_eq!("Monday".parse::<WeekDay>().unwrap(), WeekDay::Monday);
+        assert_eq!("mon".parse::<WeekDay>().unwrap(), WeekDay::Monday);
+        assert_eq!("THU".parse::<WeekDay>().unwrap(), WeekDay::Thursday);
+        assert_eq!("sAtUrDaY".parse::<WeekDay>().unwrap(), WeekDay::Saturday);
+
+        assert!(matches!("Mond".parse::<WeekDay>(), Err(WeekDayError::InvalidName)));
+        assert!(matches!("".parse::<WeekDay>(), Err(WeekDayError::InvalidName)));
+
+        for d in 0..7 {
+            let day = WeekDay::try_from(Decimal::from(d)).unwrap();
+            assert_eq!(day.to_string().parse::<WeekDay>().unwrap(), day);
+        }
+    }
+
+    #[test]
+    fn test_error_messages() {
+        fn julian_day_to_date(jd: Decimal) -> Result<CalendarDate, Box<dyn Error>> {
+            Ok(CalendarDate::try_from(JulianDay::new(jd))?)
+        }
+
+        let err = julian_day_to_date(dec!(-1)).unwrap_err();
+        assert_eq!(err.to_string(), "Julian Day must be non-negative");
+
+        assert_eq!(WeekDayError::InvalidDayNumber.to_string(), "day of the week must be between 0 (Sunday) and 6 (Saturday)");
+        assert_eq!(DateValidationError::DroppedDay.to_string(), "day was dropped by the Gregorian reform and never occurred");
+        assert_eq!(ParseError::MonthOutOfRange.to_string(), "month must be between 1 and 12");
+    }
+
+    #[test]
+    fn get_calendar_test() {
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(date.get_calendar(), Calendar::Gregorian);
+        
+        let date = CalendarDate::new(333, 1, dec!(27.5));
+        assert_eq!(date.get_calendar(), Calendar::Julian);
+
+        let date = CalendarDate::new(1582, 10, dec!(14.9));
+        assert_eq!(date.get_calendar(), Calendar::Julian);
+
+        let date = CalendarDate::new(1582, 10, dec!(15.0));
+        assert_eq!(date.get_calendar(), Calendar::Gregorian);
+    }
+
+    #[test]
+    fn test_with_calendar() {
+        // Proleptic Gregorian dates before 1582
+        let date = CalendarDate::new_in(1582, 10, dec!(14), Calendar::Gregorian);
+        assert_eq!(date.get_calendar(), Calendar::Gregorian);
+        assert_eq!(date, CalendarDate::new(1582, 10, dec!(4)));
+        assert_eq!(date.add_days(dec!(1)), CalendarDate::new(1582, 10, dec!(15)));
+
+        let date = CalendarDate::new(1500, 3, dec!(1)).with_calendar(Calendar::Gregorian);
+        assert!(!date.leap_year());
+
+        // Proleptic Julian dates after 1582
+        let date = CalendarDate::new(1900, 3, dec!(1)).with_calendar(Calendar::Julian);
+        assert!(date.leap_year());
+        assert_eq!(date, CalendarDate::new(1900, 3, dec!(14)));
+
+        let date = CalendarDate::new_in(2000, 1, dec!(1), Calendar::Julian);
+        assert_eq!(JulianDay::from(date).day, dec!(2451557.5));
+
+        let next = date.add_days(dec!(1));
+        assert_eq!(next.get_calendar(), Calendar::Julian);
+        assert_eq!((next.year(), next.month(), next.day()), (2000, 1, dec!(2)));
+
+        let next = date.add_months(1);
+        assert_eq!(next.get_calendar(), Calendar::Julian);
+        assert_eq!((next.year(), next.month(), next.day()), (2000, 2, dec!(1)));
+    }
+
+    #[test]
+    fn test_skipped_days() {
+        let last_julian = CalendarDate::new(1582, 10, dec!(4));
+        let first_gregorian = CalendarDate::new(1582, 10, dec!(15));
+        assert_eq!(CalendarDate::skipped_days(&last_julian, &first_gregorian), dec!(10));
+        assert_eq!(CalendarDate::skipped_days(&first_gregorian, &last_julian), dec!(10));
+
+        // One elapsed day, yet eleven calendar dates crossed
+        assert_eq!(CalendarDate::days_between(&last_julian, &first_gregorian), dec!(1));
+
+        let start = CalendarDate::new(1500, 1, dec!(1));
+        let end = CalendarDate::new(1600, 1, dec!(1));
+        assert_eq!(CalendarDate::skipped_days(&start, &end), dec!(10));
+        assert_eq!(CalendarDate::skipped_days(&start, &last_julian), dec!(0));
+        assert_eq!(CalendarDate::skipped_days(&first_gregorian, &end), dec!(0));
+        assert_eq!(CalendarDate::skipped_days(&start, &start), dec!(0));
+    }
+
+    #[test]
+    fn test_complete_years_since() {
+        let birth = CalendarDate::new(1990, 6, dec!(15));
+        assert_eq!(CalendarDate::new(2020, 6, dec!(14.9)).complete_years_since(&birth), 29);
+        assert_eq!(CalendarDate::new(2020, 6, dec!(15)).complete_years_since(&birth), 30);
+        assert_eq!(CalendarDate::new(2020, 12, dec!(31)).complete_years_since(&birth), 30);
+        assert_eq!(CalendarDate::new(1990, 6, dec!(15)).complete_years_since(&birth), 0);
+
+        // Leap-day birthdays are celebrated on February 28th in common years
+        let birth = CalendarDate::new(2000, 2, dec!(29));
+        assert_eq!(CalendarDate::new(2001, 2, dec!(27)).complete_years_since(&birth), 0);
+        assert_eq!(CalendarDate::new(2001, 2, dec!(28)).complete_years_since(&birth), 1);
+        assert_eq!(CalendarDate::new(2004, 2, dec!(28)).complete_years_since(&birth), 3);
+        assert_eq!(CalendarDate::new(2004, 2, dec!(29)).complete_years_since(&birth), 4);
+        assert_eq!(CalendarDate::new(2100, 2, dec!(28)).complete_years_since(&birth), 100);
+    }
+
+    #[test]
+    fn test_historical_years() {
+        let date = CalendarDate::from_historical(Era::BC, 1, 1, dec!(1)).unwrap();
+        assert_eq!(date.year(), 0);
+        assert_eq!(date.to_historical(), (Era::BC, 1));
+
+        let date = CalendarDate::from_historical(Era::BC, 584, 5, dec!(28)).unwrap();
+        assert_eq!(date.year(), -583);
+        assert_eq!(date.to_historical(), (Era::BC, 584));
+
+        let date = CalendarDate::from_historical(Era::AD, 1, 1, dec!(1)).unwrap();
+        assert_eq!(date.year(), 1);
+        assert_eq!(date.to_historical(), (Era::AD, 1));
+
+        // 1 BC December 31st is directly followed by AD 1 January 1st
+        let last_bc = CalendarDate::from_historical(Era::BC, 1, 12, dec!(31)).unwrap();
+        let first_ad = CalendarDate::from_historical(Era::AD, 1, 1, dec!(1)).unwrap();
+        assert_eq!(first_ad - last_bc, DaySpan(dec!(1)));
+    }
+
+    #[test]
+    fn test_historical_year_out_of_range() {
+        assert_eq!(CalendarDate::from_historical(Era::AD, 0, 1, dec!(1)), Err(DateValidationError::YearOutOfRange));
+        assert_eq!(CalendarDate::from_historical(Era::BC, 0, 1, dec!(1)), Err(DateValidationError::YearOutOfRange));
+        assert_eq!(CalendarDate::from_historical(Era::AD, u32::MAX, 1, dec!(1)), Err(DateValidationError::YearOutOfRange));
+        assert_eq!(CalendarDate::from_historical(Era::AD, 1582, 10, dec!(10)), Err(DateValidationError::DroppedDay));
+        assert!(CalendarDate::from_historical(Era::BC, 2147483647, 1, dec!(1)).is_ok());
+    }
+
+    #[test]
+    fn test_proleptic_gregorian() {
+        // Example 7.b is a Julian date, in the proleptic Gregorian calendar it falls one day earlier
+        let julian = CalendarDate::new(333, 1, dec!(27.5));
+        let gregorian = CalendarDate::new_in(333, 1, dec!(27.5), Calendar::Gregorian);
+        assert_eq!(JulianDay::from(julian).day, dec!(1842713.0));
+        assert_eq!(JulianDay::from(gregorian).day, dec!(1842712.0));
+
+        // Before 200 AD the proleptic Gregorian calendar is behind the Julian one instead
+        let julian = CalendarDate::new(-123, 12, dec!(31));
+        let gregorian = CalendarDate::new_in(-123, 12, dec!(31), Calendar::Gregorian);
+        assert_eq!(JulianDay::from(gregorian) - JulianDay::from(julian), dec!(3));
+
+        // Dates after the reform are unaffected
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(JulianDay::from(date.with_calendar(Calendar::Gregorian)), JulianDay::from(date));
+
+        let date = CalendarDate::new(1582, 10, dec!(4)).to_calendar(Calendar::Gregorian);
+        assert_eq!((date.year(), date.month(), date.day()), (1582, 10, dec!(14)));
+        assert_eq!(date.get_calendar(), Calendar::Gregorian);
+
+        let date = CalendarDate::new(333, 1, dec!(27.5)).to_calendar(Calendar::Gregorian);
+        assert_eq!((date.year(), date.month(), date.day()), (333, 1, dec!(28.5)));
+
+        let date = CalendarDate::new(1582, 10, dec!(4)).checked_to_calendar(Calendar::Gregorian).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1582, 10, dec!(14)));
+
+        // Dates before JulianDay 0 cannot be converted
+        assert_eq!(CalendarDate::new(-4713, 12, dec!(31)).checked_to_calendar(Calendar::Gregorian), None);
+    }
+
+    #[test]
+    fn test_julian_day_calendar_date_eq() {
+        // Example 7.a, 1957 October 4.81
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        let jd = JulianDay::new(dec!(2436116.31));
+        assert!(jd == date);
+        assert!(date == jd);
+        let (jd_ref, date_ref) = (&jd, &date);
+        assert!(jd_ref == date_ref);
+        assert!(jd != CalendarDate::new(1957, 10, dec!(4.31)));
+
+        // The calendar day begins half a Julian Day earlier
+        assert_eq!(CalendarDate::from_ymd(1957, 10, 4), JulianDay::new(dec!(2436115.5)));
+        assert_ne!(CalendarDate::from_ymd(1957, 10, 4), JulianDay::new(dec!(2436115)));
+    }
+
+    #[test]
+    fn julian_day_test() {
+        // Example 7.a
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(JulianDay::from(date).day, dec!(2436116.31));
+
+        // Example 7.b
+        let date = CalendarDate::new(333, 1, dec!(27.5));
+        assert_eq!(JulianDay::from(date).day, dec!(1842713.0));
+
+        // Gregorian dates
+        let g1 = CalendarDate::new(2000, 1, dec!(1.5));
+        let g2 = CalendarDate::new(1999, 1, dec!(1.0));
+        let g3 = CalendarDate::new(1987, 1, dec!(27.0));
+        let g4 = CalendarDate::new(1600, 12, dec!(31.0));
+
+        assert_eq!(JulianDay::from(g1).day, dec!(2451545.0));
+        assert_eq!(JulianDay::from(g2).day, dec!(2451179.5));
+        assert_eq!(JulianDay::from(g3).day, dec!(2446822.5));
+        assert_eq!(JulianDay::from(g4).day, dec!(2305812.5));
+
+        // Julian dates
+        let j1 = CalendarDate::new(837, 4, dec!(10.3));
+        let j2 = CalendarDate::new(-123, 12, dec!(31.0));
+        let j3 = CalendarDate::new(-1000, 7, dec!(12.5));
+        let j4 = CalendarDate::new(-4712, 1, dec!(1.5));
+
+        assert_eq!(JulianDay::from(j1).day, dec!(2026871.8));
+        assert_eq!(JulianDay::from(j2).day, dec!(1676496.5));
+        assert_eq!(JulianDay::from(j3).day, dec!(1356001.0));
+        assert_eq!(JulianDay::from(j4).day, dec!(0.0));
+    }
+
+    #[test]
+    fn test_fraction() {
+        let jd = JulianDay::new(dec!(2436116.31));
+        assert_eq!(jd.integer_part(), dec!(2436116));
+        assert_eq!(jd.fraction(), dec!(0.31));
+        assert_eq!(jd.civil_fraction(), dec!(0.81));
+
+        let jd = JulianDay::new(dec!(2451544.5));
+        assert_eq!(jd.fraction(), dec!(0.5));
+        assert_eq!(jd.civil_fraction(), dec!(0));
+
+        let jd = JulianDay::new(dec!(-0.25));
+        assert_eq!(jd.integer_part(), dec!(-1));
+        assert_eq!(jd.fraction(), dec!(0.75));
+        assert_eq!(jd.civil_fraction(), dec!(0.25));
+    }
+
+    #[test]
+    fn test_round_to() {
+        let jd = JulianDay::new(dec!(2437837.392451234));
+        assert_eq!(jd.round_to(5), JulianDay::new(dec!(2437837.39245)));
+        assert_eq!(jd.round_to(0), JulianDay::new(dec!(2437837)));
+        assert_eq!(JulianDay::new(dec!(2451544.5)).round_to(0), JulianDay::new(dec!(2451544)));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let jd = JulianDay::new(dec!(2451545.0));
+        assert!(jd.approx_eq(&JulianDay::new(dec!(2451545.00001)), dec!(0.0001)));
+        assert!(jd.approx_eq(&JulianDay::new(dec!(2451544.9999)), dec!(0.0001)));
+        assert!(!jd.approx_eq(&JulianDay::new(dec!(2451545.001)), dec!(0.0001)));
+    }
+
+    #[test]
+    fn test_centuries_since_j2000() {
+        let j2000 = JulianDay::new(dec!(2451545.0));
+        assert_eq!(j2000.centuries_since_j2000(), dec!(0));
+        assert_eq!(j2000.millennia_since_j2000(), dec!(0));
+
+        let jd = JulianDay::new(dec!(2446895.5));
+        assert_eq!(jd.centuries_since_j2000().round_dp(12), dec!(-0.127296372348));
+        assert_eq!(jd.millennia_since_j2000().round_dp(13), dec!(-0.0127296372348));
+    }
+
+    #[test]
+    fn test_to_dynamical_time() {
+        // Example 10.a, 1977 February 18 at 3h37m40s UT is 3h38m28s TD
+        let ut = JulianDay::from(CalendarDate::with_time(1977, 2, 18, 3, 37, dec!(40)));
+        let td = CalendarDate::try_from(ut.to_dynamical_time(1977)).unwrap();
+
+        let (h, m, s) = td.time_of_day();
+        assert_eq!((h, m, s.round()), (3, 38, dec!(28)));
+    }
+
+    #[test]
+    fn test_epochs() {
+        assert_eq!(JulianDay::J2000, JulianDay::from(CalendarDate::new(2000, 1, dec!(1.5))));
+        assert_eq!(JulianDay::UNIX_EPOCH, JulianDay::from(CalendarDate::new(1970, 1, dec!(1))));
+        assert_eq!(JulianDay::MJD_EPOCH, JulianDay::from(CalendarDate::new(1858, 11, dec!(17))));
+        assert_eq!(JulianDay::GREGORIAN_REFORM, JulianDay::from(CalendarDate::new(1582, 10, dec!(15))));
+
+        let last_julian = CalendarDate::new(1582, 10, dec!(4));
+        assert_eq!(JulianDay::GREGORIAN_REFORM - JulianDay::from(last_julian), dec!(1));
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        let epoch = JulianDay::from(CalendarDate::new(1970, 1, dec!(1)));
+        assert_eq!(JulianDay::from_unix_timestamp(0), epoch);
+        assert_eq!(epoch.to_unix_timestamp(), Some(0));
+
+        let j2000 = JulianDay::from(CalendarDate::new(2000, 1, dec!(1)));
+        assert_eq!(JulianDay::from_unix_timestamp(946684800), j2000);
+        assert_eq!(j2000.to_unix_timestamp(), Some(946684800));
+
+        assert_eq!((epoch + dec!(1.5)).to_unix_timestamp(), Some(129600));
+        assert_eq!(JulianDay::from_unix_timestamp(-86400), epoch - dec!(1));
+        assert_eq!((epoch - dec!(0.00001)).to_unix_timestamp(), None);
+        assert_eq!(JulianDay::new(Decimal::MAX).to_unix_timestamp(), None);
+    }
+
+    #[test]
+    fn test_from_explicit_calendar() {
+        // Example 7.a, 1957 October 4.81 (Gregorian) and Example 7.b, 333 January 27.5 (Julian)
+        assert_eq!(JulianDay::from_gregorian(1957, 10, dec!(4.81)), JulianDay::new(dec!(2436116.31)));
+        assert_eq!(JulianDay::from_julian(333, 1, dec!(27.5)), JulianDay::new(dec!(1842713.0)));
+
+        // The same calendar date is 10 days apart in 1582, whichever side of the reform it falls on
+        assert_eq!(JulianDay::from_julian(1582, 10, dec!(15)) - JulianDay::from_gregorian(1582, 10, dec!(15)), dec!(10));
+        assert_eq!(JulianDay::from_gregorian(1582, 10, dec!(4)), JulianDay::from(CalendarDate::new(1582, 9, dec!(24))));
+        assert_eq!(JulianDay::from_julian(2000, 1, dec!(1.5)), JulianDay::from(CalendarDate::new(2000, 1, dec!(14.5))));
+    }
+
+    #[test]
+    fn test_unix_timestamp_leap_seconds() {
+        // A leap second was inserted as 2016 December 31st 23:59:60 UTC, raising TAI - UTC from 36 to 37 seconds
+        let before = 1483228799;
+        let after = 1483228800;
+        assert_eq!(JulianDay::from_unix_timestamp_tai(before), JulianDay::from_unix_timestamp(before + 36));
+        assert_eq!(JulianDay::from_unix_timestamp_tai(after), JulianDay::from_unix_timestamp(after + 37));
+        let elapsed = JulianDay::from_unix_timestamp_tai(after) - JulianDay::from_unix_timestamp_tai(before);
+        assert_eq!((elapsed * dec!(86400)).round_dp(6), dec!(2));
+
+        for secs in [0, 78796799, 78796800, before, after, 1700000000] {
+            assert_eq!(JulianDay::from_unix_timestamp_tai(secs).to_unix_timestamp_utc(), Some(secs));
+        }
+
+        // The leap second itself has no Unix timestamp of its own
+        assert_eq!(JulianDay::from_unix_timestamp(after + 36).to_unix_timestamp_utc(), Some(after));
+    }
+
+    #[test]
+    fn test_epochs_julian_besselian() {
+        assert_eq!(JulianDay::J2000.to_julian_epoch(), dec!(2000));
+        assert_eq!(JulianDay::from_julian_epoch(dec!(2000.0)), JulianDay::new(dec!(2451545.0)));
+        assert_eq!(JulianDay::from_julian_epoch(dec!(2050.0)).day, dec!(2469807.5));
+
+        // B1950.0 is JD 2433282.4235
+        let b1950 = JulianDay::from_besselian_epoch(dec!(1950.0));
+        assert_eq!(b1950.round_to(4), JulianDay::new(dec!(2433282.4235)));
+        assert_eq!(b1950.to_besselian_epoch(), dec!(1950));
+        assert_eq!(JulianDay::J2000.to_besselian_epoch().round_dp(4), dec!(2000.0013));
+    }
+
+    #[test]
+    fn test_julian_day_display() {
+        // Example 7.a, 1957 October 4.81
+        let jd = JulianDay::new(dec!(2436116.31));
+        assert_eq!(jd.to_string(), "2436116.31");
+        assert_eq!(jd.to_string_fixed(5), "2436116.31000");
+        assert_eq!(jd.to_string_fixed(1), "2436116.3");
+        assert_eq!(jd.to_string_fixed(0), "2436116");
+        assert_eq!(format!("{:.5}", jd), "2436116.31000");
+        assert_eq!(format!("{:#.5}", jd), "2436116.31000 JD");
+        assert_eq!(format!("{:#}", JulianDay::J2000), "2451545.0 JD");
+    }
+
+    #[test]
+    fn test_modified_julian_day() {
+        // J2000.0
+        let jd = JulianDay::new(dec!(2451545.0));
+        assert_eq!(jd.to_modified(), dec!(51544.5));
+        assert_eq!(JulianDay::from_modified(dec!(51544.5)), jd);
+
+        let mjd = ModifiedJulianDay::from(jd);
+        assert_eq!(mjd, ModifiedJulianDay(dec!(51544.5)));
+
+        let back: JulianDay = mjd.into();
+        assert_eq!(back, jd);
+
+        // The MJD epoch is 1858 November 17 at 0h
+        let epoch = CalendarDate::new(1858, 11, dec!(17));
+        assert_eq!(JulianDay::from(epoch).to_modified(), dec!(0));
+    }
+
+    #[test]
+    fn test_julian_day_arithmetic() {
+        let jd = JulianDay::new(dec!(2436116.31));
+
+        let next = CalendarDate::try_from(jd + dec!(1.0)).unwrap();
+        assert_eq!(next, CalendarDate::new(1957, 10, dec!(5.81)));
+
+        let prev = CalendarDate::try_from(jd - dec!(1.0)).unwrap();
+        assert_eq!(prev, CalendarDate::new(1957, 10, dec!(3.81)));
+
+        // Crossing a month boundary
+        let jd = JulianDay::from(CalendarDate::new(2000, 2, dec!(29)));
+        let next = CalendarDate::try_from(jd + dec!(1.0)).unwrap();
+        assert_eq!(next, CalendarDate::new(2000, 3, dec!(1)));
+
+        assert_eq!((jd + dec!(0.5)) - jd, dec!(0.5));
+        assert_eq!(jd - (jd + dec!(2.25)), dec!(-2.25));
+    }
+
+    #[cfg(feature = "std-clock")]
+    #[test]
+    fn test_now() {
+        assert!(JulianDay::now().day > JulianDay::J2000.day);
+
+        let today = CalendarDate::today();
+        assert!(today > CalendarDate::new(2000, 1, dec!(1.5)));
+        assert_eq!(today.day().fract(), Decimal::ZERO);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let jd = JulianDay::new(dec!(2436116.31));
+        let json = serde_json::to_string(&jd).unwrap();
+        assert_eq!(json, "2436116.31");
+        assert_eq!(serde_json::from_str::<JulianDay>(&json).unwrap(), jd);
+
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, r#"{"year":1957,"month":10,"day":4.81}"#);
+        assert_eq!(serde_json::from_str::<CalendarDate>(&json).unwrap(), date);
+
+        let json = serde_json::to_string(&WeekDay::Friday).unwrap();
+        assert_eq!(json, r#""Friday""#);
+        assert_eq!(serde_json::from_str::<WeekDay>(&json).unwrap(), WeekDay::Friday);
+
+        let json = serde_json::to_string(&Calendar::Julian).unwrap();
+        assert_eq!(json, r#""Julian""#);
+        assert_eq!(serde_json::from_str::<Calendar>(&json).unwrap(), Calendar::Julian);
+    }
+
+    #[test]
+    fn test_get_calendar_date() {
+        let jd = JulianDay::new(dec!(2436116.31));
+        let cd = CalendarDate::try_from(jd).unwrap();
+
+        assert_eq!(cd, CalendarDate::new(1957, 10, dec!(4.81)));
+        assert_eq!(cd.year(), 1957);
+        assert_eq!(cd.month(), 10);
+        assert_eq!(cd.day(), dec!(4.81));
+    }
+
+    #[test]
+    fn test_get_calendar_date_reform_seam() {
+        let seam = [
+            (dec!(2299159.5), CalendarDate::new(1582, 10, dec!(4))),
+            (dec!(2299160.0), CalendarDate::new(1582, 10, dec!(4.5))),
+            (dec!(2299160.49), CalendarDate::new(1582, 10, dec!(4.99))),
+            (dec!(2299160.5), CalendarDate::new(1582, 10, dec!(15))),
+            (dec!(2299161.0), CalendarDate::new(1582, 10, dec!(15.5))),
+            (dec!(2299161.5), CalendarDate::new(1582, 10, dec!(16))),
+            (dec!(2299158.5), CalendarDate::new(1582, 10, dec!(3))),
+        ];
+
+        for (jd, date) in seam {
+            let cd = CalendarDate::try_from(JulianDay::new(jd)).unwrap();
+            assert_eq!((cd.year(), cd.month(), cd.day()), (date.year(), date.month(), date.day()));
+            assert_eq!(JulianDay::from(date).day, jd);
+        }
+    }
+
+    #[test]
+    fn test_interval_between() {
+        let first = CalendarDate::new(1910, 4, dec!(20));
+        let second = CalendarDate::new(1986, 2, dec!(9));
+        
+        assert_eq!(CalendarDate::days_between(&first, &second), dec!(27689));
+
+        // 27689 days are 3955 weeks and 4 days
+        assert_eq!(CalendarDate::weeks_between(&first, &second), dec!(27689) / dec!(7));
+        assert_eq!(CalendarDate::weeks_between(&second, &first), CalendarDate::weeks_between(&first, &second));
+        assert_eq!(CalendarDate::whole_weeks_between(&first, &second), 3955);
+        assert_eq!(CalendarDate::whole_weeks_between(&first, &first.add_days(dec!(6.9))), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Every integer-day date from -1000 to 3000 must survive CalendarDate -> JulianDay -> CalendarDate,
+        // and consecutive dates must be exactly one day apart
+        let mut previous = JulianDay::from(CalendarDate::new(-1001, 12, dec!(31)));
+
+        for y in -1000..=3000 {
+            let leap_year = CalendarDate::new(y, 1, Decimal::ONE).leap_year();
+            for m in 1..=12 {
+                for d in 1..=month_length(m, leap_year) {
+                    let Ok(date) = CalendarDate::try_new(y, m, Decimal::from(d)) else {
+                        continue;
+                    };
+
+                    let jd = JulianDay::from(date);
+                    assert_eq!(jd.day - previous.day, Decimal::ONE, "{} does not follow the previous date", date);
+                    previous = jd;
+
+                    let back = CalendarDate::try_from(jd).unwrap();
+                    assert_eq!((back.year(), back.month(), back.day()), (y, m, Decimal::from(d)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_days() {
+        let dates = [
+            CalendarDate::new(1910, 4, dec!(20)),
+            CalendarDate::new(1986, 2, dec!(9)),
+            CalendarDate::new(1986, 3, dec!(1.5)),
+        ];
+
+        assert_eq!(CalendarDate::total_days(&dates), dec!(27709.5));
+        assert_eq!(CalendarDate::total_days(&dates[..1]), dec!(0));
+        assert_eq!(CalendarDate::total_days(&[]), dec!(0));
+
+        // Going back in time counts negatively
+        let dates = [dates[2], dates[0], dates[1]];
+        assert_eq!(CalendarDate::total_days(&dates), dec!(-20.5));
+    }
+
+    #[test]
+    fn test_sub() {
+        let first = CalendarDate::new(1910, 4, dec!(20));
+        let second = CalendarDate::new(1986, 2, dec!(9));
+
+        assert_eq!(second - first, DaySpan(dec!(27689)));
+
+        let (lhs, rhs) = (&first, &second);
+        assert_eq!(lhs - rhs, DaySpan(dec!(-27689)));
+        assert_eq!(second - first, DaySpan(CalendarDate::difference(&second, &first)));
+    }
+
+    #[test]
+    fn test_day_span_describe() {
+        let earlier = CalendarDate::from_ymd(2000, 1, 1);
+        let later = CalendarDate::from_ymd(2000, 1, 4);
+        assert_eq!((earlier - later).describe(), "3 days before");
+        assert_eq!((later - earlier).describe(), "3 days after");
+        assert_eq!((earlier - earlier).describe(), "same day");
+        assert_eq!(DaySpan(dec!(0.0)).describe(), "same day");
+
+        assert_eq!(DaySpan(dec!(1)).describe(), "1 day after");
+        assert_eq!(DaySpan(dec!(-1.0)).describe(), "1 day before");
+        assert_eq!(DaySpan(dec!(-0.25)).describe(), "0.25 days before");
+        assert_eq!(DaySpan(dec!(2.50)).describe(), "2.5 days after");
+
+        assert_eq!(-(earlier - later), later - earlier);
+        assert_eq!((-DaySpan(dec!(3))).describe(), "3 days before");
+    }
+
+    #[test]
+    fn test_day_span() {
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        let span = DaySpan(dec!(10.5));
+
+        assert_eq!(date + span, CalendarDate::new(1957, 10, dec!(15.31)));
+        assert_eq!(date + span - span, date);
+        assert_eq!(span.as_days(), dec!(10.5));
+        assert_eq!(span.as_weeks(), dec!(1.5));
+        assert_eq!(span.whole_days(), 10);
+        assert_eq!(DaySpan(dec!(-10.5)).whole_days(), -10);
+        assert_eq!(span + span - DaySpan(dec!(1)), DaySpan(dec!(20)));
+        assert_eq!(span.to_string(), "10.5 days");
+    }
+
+    #[test]
+    fn test_ordering() {
+        let last_julian = CalendarDate::new(1582, 10, dec!(4));
+        let first_gregorian = CalendarDate::new(1582, 10, dec!(15));
+
+        assert!(last_julian < first_gregorian);
+        assert_eq!(first_gregorian - last_julian, DaySpan(dec!(1)));
+
+        let mut dates = vec![
+            CalendarDate::new(1957, 10, dec!(4.81)),
+            first_gregorian,
+            CalendarDate::new(333, 1, dec!(27.5)),
+            last_julian,
+        ];
+        dates.sort();
+
+        assert_eq!(dates, vec![
+            CalendarDate::new(333, 1, dec!(27.5)),
+            last_julian,
+            first_gregorian,
+            CalendarDate::new(1957, 10, dec!(4.81)),
+        ]);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let start = CalendarDate::new(1582, 10, dec!(1));
+        let end = CalendarDate::new(1582, 10, dec!(31));
+
+        assert_eq!(CalendarDate::new(1582, 9, dec!(30)).clamp(start, end), start);
+        assert_eq!(CalendarDate::new(1582, 10, dec!(4)).clamp(start, end), CalendarDate::new(1582, 10, dec!(4)));
+        assert_eq!(CalendarDate::new(1582, 10, dec!(15)).clamp(start, end), CalendarDate::new(1582, 10, dec!(15)));
+        assert_eq!(CalendarDate::new(1582, 11, dec!(1)).clamp(start, end), end);
+
+        // A date in another calendar is compared by the instant it corresponds to
+        let julian = CalendarDate::new_in(1582, 10, dec!(25), Calendar::Julian);
+        assert_eq!(julian.clamp(start, end), end);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashMap;
+
+        let mut holidays = HashMap::new();
+        holidays.insert(CalendarDate::new(2000, 1, dec!(1)), "New Year's Day");
+
+        // Trailing zeros in the day must not affect the lookup
+        assert_eq!(holidays.get(&CalendarDate::new(2000, 1, dec!(1.000))), Some(&"New Year's Day"));
+        assert_eq!(holidays.get(&CalendarDate::new(2000, 1, dec!(2))), None);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(CalendarDate::try_new(1957, 10, dec!(4.81)), Ok(CalendarDate::new(1957, 10, dec!(4.81))));
+        assert_eq!(CalendarDate::try_new(2000, 2, dec!(29.99)), Ok(CalendarDate::new(2000, 2, dec!(29.99))));
+        assert_eq!(CalendarDate::try_new(1500, 2, dec!(29)), Ok(CalendarDate::new(1500, 2, dec!(29))));
+        assert_eq!(CalendarDate::try_new(1582, 10, dec!(4.99)), Ok(CalendarDate::new(1582, 10, dec!(4.99))));
+        assert_eq!(CalendarDate::try_new(1582, 10, dec!(15)), Ok(CalendarDate::new(1582, 10, dec!(15))));
+
+        assert_eq!(CalendarDate::try_new(1957, 13, dec!(1)), Err(DateValidationError::MonthOutOfRange));
+        assert_eq!(CalendarDate::try_new(1957, 0, dec!(1)), Err(DateValidationError::MonthOutOfRange));
+        assert_eq!(CalendarDate::try_new(1957, 1, dec!(32)), Err(DateValidationError::DayOutOfRange));
+        assert_eq!(CalendarDate::try_new(1957, 1, dec!(0.5)), Err(DateValidationError::DayOutOfRange));
+        assert_eq!(CalendarDate::try_new(1900, 2, dec!(29)), Err(DateValidationError::DayOutOfRange));
+        assert_eq!(CalendarDate::try_new(1582, 10, dec!(5)), Err(DateValidationError::DroppedDay));
+        assert_eq!(CalendarDate::try_new(1582, 10, dec!(14.9)), Err(DateValidationError::DroppedDay));
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(CalendarDate::days_in_month(1900, 2), 28);
+        assert_eq!(CalendarDate::days_in_month(2000, 2), 29);
+        assert_eq!(CalendarDate::days_in_month(2001, 2), 28);
+        assert_eq!(CalendarDate::days_in_month(1500, 2), 29);
+        assert_eq!(CalendarDate::days_in_month(1582, 2), 28);
+        assert_eq!(CalendarDate::days_in_month(1582, 10), 21);
+        assert_eq!(CalendarDate::days_in_month(1582, 11), 30);
+        assert_eq!(CalendarDate::days_in_month(2024, 1), 31);
+    }
+
+    #[test]
+    fn test_month_days() {
+        let days: Vec<(CalendarDate, WeekDay)> = CalendarDate::month_days(1582, 10).collect();
+        assert_eq!(days.len(), 21);
+        assert_eq!(days[3], (CalendarDate::new(1582, 10, dec!(4)), WeekDay::Thursday));
+        assert_eq!(days[4], (CalendarDate::new(1582, 10, dec!(15)), WeekDay::Friday));
+        assert_eq!(days[20].0, CalendarDate::new(1582, 10, dec!(31)));
+
+        let days: Vec<(CalendarDate, WeekDay)> = CalendarDate::month_days(2023, 12).collect();
+        assert_eq!(days.len(), 31);
+        assert_eq!(days[0], (CalendarDate::new(2023, 12, dec!(1)), WeekDay::Friday));
+        assert_eq!(days[30], (CalendarDate::new(2023, 12, dec!(31)), WeekDay::Sunday));
+    }
+
+    #[test]
+    fn test_last_day_of_month() {
+        assert_eq!(CalendarDate::new(1900, 2, dec!(10.5)).last_day_of_month(), CalendarDate::new(1900, 2, dec!(28)));
+        assert_eq!(CalendarDate::new(2000, 2, dec!(1)).last_day_of_month(), CalendarDate::new(2000, 2, dec!(29)));
+        assert_eq!(CalendarDate::new(1500, 2, dec!(1)).last_day_of_month(), CalendarDate::new(1500, 2, dec!(29)));
+        assert_eq!(CalendarDate::new(1582, 10, dec!(1)).last_day_of_month(), CalendarDate::new(1582, 10, dec!(31)));
+
+        let date = CalendarDate::new_in(1900, 2, dec!(1), Calendar::Julian);
+        assert_eq!(date.last_day_of_month().day(), dec!(29));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let date = CalendarDate::new(2000, 1, dec!(32.5)).normalize();
+        assert_eq!((date.year(), date.month(), date.day()), (2000, 2, dec!(1.5)));
+
+        let date = CalendarDate::new(1999, 12, dec!(62)).normalize();
+        assert_eq!((date.year(), date.month(), date.day()), (2000, 1, dec!(31)));
+
+        let date = CalendarDate::new(2000, 3, dec!(0)).normalize();
+        assert_eq!((date.year(), date.month(), date.day()), (2000, 2, dec!(29)));
+
+        let date = CalendarDate::new(1957, 10, dec!(4.81)).normalize();
+        assert_eq!((date.year(), date.month(), date.day()), (1957, 10, dec!(4.81)));
+
+        // The dropped days of 1582 October are interpreted in the Julian calendar
+        let date = CalendarDate::new(1582, 10, dec!(10)).normalize();
+        assert_eq!((date.year(), date.month(), date.day()), (1582, 10, dec!(20)));
+
+        let date = CalendarDate::new(2000, 1, dec!(32.5)).checked_normalize().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2000, 2, dec!(1.5)));
+
+        // Dates before JulianDay 0 cannot be normalized
+        assert_eq!(CalendarDate::new(-4713, 12, dec!(31)).checked_normalize(), None);
+        assert!(CalendarDate::new(-4712, 1, dec!(1.5)).checked_normalize().is_some());
+    }
+
+    #[test]
+    fn test_add_days() {
+        let date = CalendarDate::new(2000, 1, dec!(1.5));
+        assert_eq!(date.add_days(dec!(30)), CalendarDate::new(2000, 1, dec!(31.5)));
+        assert_eq!(date.add_days(dec!(60)), CalendarDate::new(2000, 3, dec!(1.5)));
+        assert_eq!(date.add_days(dec!(-1.5)), CalendarDate::new(1999, 12, dec!(31)));
+
+        // 1582 October 4th (Julian) was followed directly by October 15th (Gregorian)
+        let date = CalendarDate::new(1582, 10, dec!(4));
+        assert_eq!(date.add_days(dec!(1)), CalendarDate::new(1582, 10, dec!(15)));
+        assert_eq!(date.add_days(dec!(1)).add_days(dec!(-1)), date);
+    }
+
+    #[test]
+    fn test_julian_day_weekday() {
+        // Example 7.e, 1954 June 30.0 was a Wednesday
+        assert_eq!(JulianDay::new(dec!(2434923.5)).weekday(), WeekDay::Wednesday);
+
+        // The civil day runs from one midnight (x.5) to the next
+        assert_eq!(JulianDay::new(dec!(2434923.99)).weekday(), WeekDay::Wednesday);
+        assert_eq!(JulianDay::new(dec!(2434924.49)).weekday(), WeekDay::Wednesday);
+        assert_eq!(JulianDay::new(dec!(2434924.5)).weekday(), WeekDay::Thursday);
+        assert_eq!(JulianDay::new(dec!(2434923.49)).weekday(), WeekDay::Tuesday);
+
+        // JulianDay 0 was a Monday, and the days before it follow on
+        assert_eq!(JulianDay::new(Decimal::ZERO).weekday(), WeekDay::Monday);
+        assert_eq!(JulianDay::new(dec!(-1)).weekday(), WeekDay::Sunday);
+
+        let date = CalendarDate::new(1582, 10, dec!(15.75));
+        assert_eq!(JulianDay::from(date).weekday(), date.day_of_the_week());
+    }
+
+    #[test]
+    fn test_julian_day_json() {
+        let jd = JulianDay::new(dec!(2436116.31));
+        assert_eq!(jd.to_json(), "2436116.31");
+        assert_eq!(JulianDay::from_json(&jd.to_json()).unwrap(), jd);
+        assert_eq!(JulianDay::from_json(" -1.5 ").unwrap(), JulianDay::new(dec!(-1.5)));
+        assert_eq!(JulianDay::from_json("2.4515450e6").unwrap(), JulianDay::J2000);
+        assert!(JulianDay::from_json("\"2451545.0\"").is_err());
+        assert!(JulianDay::from_json("").is_err());
+    }
+
+    #[test]
+    fn test_calendar_date_json() {
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(date.to_json(), r#"{"year":1957,"month":10,"day":4.81}"#);
+
+        let decoded = CalendarDate::from_json(&date.to_json()).unwrap();
+        assert_eq!((decoded.y, decoded.m, decoded.d, decoded.calendar), (1957, 10, dec!(4.81), None));
+
+        // A forced calendar survives the round trip
+        let date = CalendarDate::new_in(-584, 5, dec!(28), Calendar::Julian);
+        assert_eq!(date.to_json(), r#"{"year":-584,"month":5,"day":28,"calendar":"Julian"}"#);
+        let decoded = CalendarDate::from_json(&date.to_json()).unwrap();
+        assert_eq!((decoded.y, decoded.m, decoded.d, decoded.calendar), (-584, 5, dec!(28), Some(Calendar::Julian)));
+
+        // So does a reform date
+        let date = CalendarDate::from_ymd(1752, 9, 2).with_reform(JulianDay::BRITISH_REFORM);
+        assert_eq!(date.to_json(), r#"{"year":1752,"month":9,"day":2,"reform":2361221.5}"#);
+        assert_eq!(CalendarDate::from_json(&date.to_json()).unwrap().get_reform(), JulianDay::BRITISH_REFORM);
+
+        // Fields may be reordered and spaced out
+        let decoded = CalendarDate::from_json(r#" { "day": 1.5, "year": 2000, "month": 1 } "#).unwrap();
+        assert_eq!(decoded, CalendarDate::new(2000, 1, dec!(1.5)));
+
+        assert!(matches!(CalendarDate::from_json(r#"{"year":2000,"month":13,"day":1}"#), Err(ParseError::MonthOutOfRange)));
+        assert!(matches!(CalendarDate::from_json(r#"{"year":2001,"month":2,"day":29}"#), Err(ParseError::DayOutOfRange)));
+        assert!(matches!(CalendarDate::from_json(r#"{"year":2000,"month":1}"#), Err(ParseError::InvalidJson)));
+        assert!(matches!(CalendarDate::from_json(r#"{"year":2000,"month":1,"day":1,"era":"AD"}"#), Err(ParseError::InvalidJson)));
+        assert!(matches!(CalendarDate::from_json("2000-01-01"), Err(ParseError::InvalidJson)));
+    }
+
+    #[test]
+    fn test_to_julian_day_with_offset() {
+        // Example 7.a, 1957 October 4.81 UT
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(date.to_julian_day_with_offset(Decimal::ZERO), JulianDay::new(dec!(2436116.31)));
+
+        // Noon at UTC+2 is 10h UT, noon at UTC-6 is 18h UT
+        let noon = CalendarDate::new(2000, 1, dec!(1.5));
+        assert_eq!(noon.to_julian_day_with_offset(dec!(2)), JulianDay::J2000 - dec!(2) / dec!(24));
+        assert_eq!(noon.to_julian_day_with_offset(dec!(-6)), JulianDay::J2000 + dec!(0.25));
+
+        // Offsets can carry the instant into the previous UTC day
+        let midnight = CalendarDate::from_ymd(2000, 1, 1);
+        assert_eq!(midnight.to_julian_day_with_offset(dec!(5.5)), CalendarDate::with_time(1999, 12, 31, 18, 30, Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_rata_die() {
+        assert_eq!(CalendarDate::from_ymd(1, 1, 1).to_rata_die(), 1);
+        assert_eq!(CalendarDate::new(2000, 1, dec!(1.75)).to_rata_die(), 730120);
+        assert_eq!(CalendarDate::from_ymd(2024, 2, 29).to_rata_die(), 738945);
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 15).to_rata_die(), 577736);
+
+        // Before the reform, the day numbers are Gregorian rather than historical
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 4).to_rata_die(), 577725);
+
+        assert_eq!(CalendarDate::from_rata_die(1).to_string(), "0001-01-01");
+        assert_eq!(CalendarDate::from_rata_die(730120), CalendarDate::from_ymd(2000, 1, 1));
+        let date = CalendarDate::from_rata_die(577735);
+        assert_eq!((date.year(), date.month(), date.day(), date.get_calendar()), (1582, 10, dec!(14), Calendar::Gregorian));
+        assert_eq!(date, CalendarDate::from_ymd(1582, 10, 4));
+
+        for rd in [-1000, 0, 1, 577735, 738945] {
+            assert_eq!(CalendarDate::from_rata_die(rd).to_rata_die(), rd);
+        }
+
+        // Rata Die -1721424 is the first whole day after JulianDay 0
+        let date = CalendarDate::checked_from_rata_die(-1721424).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (-4713, 11, dec!(25)));
+        assert_eq!(CalendarDate::checked_from_rata_die(-1721425), None);
+        assert_eq!(CalendarDate::checked_from_rata_die(i64::MIN), None);
+        assert_eq!(CalendarDate::checked_from_rata_die(i64::MAX), None);
+        assert_eq!(CalendarDate::checked_from_rata_die(738945), Some(CalendarDate::from_ymd(2024, 2, 29)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_rata_die_before_julian_day_zero() {
+        CalendarDate::from_rata_die(-1721425);
+    }
+
+    #[test]
+    fn test_cmp_day_only() {
+        let morning = CalendarDate::new(2000, 1, dec!(1.25));
+        let evening = CalendarDate::new(2000, 1, dec!(1.75));
+        assert_eq!(morning.cmp_day_only(&evening), Ordering::Equal);
+        assert_eq!(morning.cmp(&evening), Ordering::Less);
+        assert_eq!(evening.cmp_day_only(&CalendarDate::new(2000, 1, dec!(2.1))), Ordering::Less);
+        assert_eq!(evening.cmp_day_only(&CalendarDate::new(1999, 12, dec!(31.9))), Ordering::Greater);
+
+        // The sort is stable, so events of the same day keep their order
+        let mut events = vec![
+            CalendarDate::new(2000, 1, dec!(2.1)),
+            CalendarDate::new(2000, 1, dec!(1.75)),
+            CalendarDate::new(2000, 1, dec!(1.25)),
+        ];
+        events.sort_by(CalendarDate::cmp_day_only);
+        assert_eq!(events, vec![
+            CalendarDate::new(2000, 1, dec!(1.75)),
+            CalendarDate::new(2000, 1, dec!(1.25)),
+            CalendarDate::new(2000, 1, dec!(2.1)),
+        ]);
+
+        // The same day in two calendars
+        let julian = CalendarDate::new(1582, 10, dec!(4.9));
+        assert_eq!(julian.to_calendar(Calendar::Gregorian).with_day(dec!(14.1)).cmp_day_only(&julian), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_count_weekday() {
+        // 2024 is a leap year beginning on a Monday
+        assert_eq!(CalendarDate::count_weekday(2024, WeekDay::Monday), 53);
+        assert_eq!(CalendarDate::count_weekday(2024, WeekDay::Tuesday), 53);
+        assert_eq!(CalendarDate::count_weekday(2024, WeekDay::Friday), 52);
+        assert_eq!(CalendarDate::count_weekday(2024, WeekDay::Sunday), 52);
+
+        // 2023 is a common year beginning on a Sunday
+        assert_eq!(CalendarDate::count_weekday(2023, WeekDay::Sunday), 53);
+        assert_eq!(CalendarDate::count_weekday(2023, WeekDay::Monday), 52);
+
+        // 1582 began on a Monday and lost ten days in October
+        assert_eq!(CalendarDate::count_weekday(1582, WeekDay::Friday), 51);
+        assert_eq!(CalendarDate::count_weekday(1582, WeekDay::Saturday), 50);
+    }
+
+    #[test]
+    fn test_from_julian_day_rounded() {
+        // Example 7.a gives 1957 October 4.81 for JulianDay 2436116.31
+        let round = |jd| CalendarDate::from_julian_day_rounded(JulianDay::new(jd)).unwrap().to_string();
+        assert_eq!(round(dec!(2436116.31)), "1957-10-05");
+        assert_eq!(round(dec!(2436115.99)), "1957-10-04");
+        assert_eq!(round(dec!(2436116.01)), "1957-10-05");
+
+        // Just below and just after midnight
+        assert_eq!(round(dec!(2436116.49)), "1957-10-05");
+        assert_eq!(round(dec!(2436116.51)), "1957-10-05");
+
+        // Noon rounds up
+        assert_eq!(round(dec!(2436116.0)), "1957-10-05");
+        assert_eq!(CalendarDate::from_julian_day_rounded(JulianDay::new(dec!(2436116.0))).unwrap().day(), dec!(5));
+
+        // Rounding up from 1582 October 4 lands on October 15, and carries over months and years
+        assert_eq!(round(dec!(2299160.2)), "1582-10-15");
+        assert_eq!(round(dec!(2451544.4)), "2000-01-01");
+
+        assert!(CalendarDate::from_julian_day_rounded(JulianDay::new(dec!(-0.6))).is_err());
+    }
+
+    #[test]
+    fn test_checked_add_days() {
+        let date = CalendarDate::new(2000, 1, dec!(1.5));
+        assert_eq!(date.checked_add_days(dec!(60)), Some(date.add_days(dec!(60))));
+        assert_eq!(date.checked_add_days(dec!(-2451545)), Some(CalendarDate::new(-4712, 1, dec!(1.5))));
+
+        // Before JulianDay 0
+        assert_eq!(date.checked_add_days(dec!(-2451546)), None);
+
+        // Years beyond i32::MAX, and overflowing decimals
+        assert_eq!(date.checked_add_days(dec!(780000000000)).map(|d| d.year() > 2_000_000_000), Some(true));
+        assert_eq!(date.checked_add_days(dec!(790000000000)), None);
+        assert_eq!(date.checked_add_days(Decimal::MAX), None);
+        assert_eq!(date.checked_add_days(Decimal::MIN), None);
+    }
+
+    #[test]
+    fn test_succ_pred() {
+        let date = CalendarDate::new(2000, 2, dec!(28.5));
+        assert_eq!(date.succ(), CalendarDate::new(2000, 2, dec!(29.5)));
+        assert_eq!(date.succ().succ(), CalendarDate::new(2000, 3, dec!(1.5)));
+        assert_eq!(date.pred(), CalendarDate::new(2000, 2, dec!(27.5)));
+        assert_eq!(CalendarDate::from_ymd(2000, 1, 1).pred().to_string(), "1999-12-31");
+
+        // The 1582 gap is bridged in both directions
+        let date = CalendarDate::from_ymd(1582, 10, 4);
+        assert_eq!(date.succ().to_string(), "1582-10-15");
+        assert_eq!(date.succ().pred().to_string(), "1582-10-04");
+    }
+
+    #[test]
+    fn test_floor_to_month() {
+        assert_eq!(CalendarDate::new(2024, 3, dec!(15.75)).floor_to_month(), CalendarDate::new(2024, 3, dec!(1)));
+        assert_eq!(CalendarDate::from_ymd(2024, 3, 1).floor_to_month(), CalendarDate::from_ymd(2024, 3, 1));
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 20).floor_to_month().to_string(), "1582-10-01");
+    }
+
+    #[test]
+    fn test_ceil_to_week() {
+        // Tuesday 2024 January 30th rounds up across the month boundary
+        let date = CalendarDate::from_ymd(2024, 1, 30);
+        assert_eq!(date.ceil_to_week(WeekDay::Monday), CalendarDate::from_ymd(2024, 2, 5));
+        assert_eq!(date.ceil_to_week(WeekDay::Sunday), CalendarDate::from_ymd(2024, 2, 4));
+        assert_eq!(date.ceil_to_week(WeekDay::Tuesday), date);
+
+        // A later time on the boundary day rounds up to the next week
+        let date = CalendarDate::new(2024, 1, dec!(30.25));
+        assert_eq!(date.ceil_to_week(WeekDay::Tuesday), CalendarDate::from_ymd(2024, 2, 6));
+        assert_eq!(date.ceil_to_week(WeekDay::Wednesday), CalendarDate::from_ymd(2024, 1, 31));
+
+        // Thursday 1582 October 4th is followed by Friday 1582 October 15th
+        let date = CalendarDate::from_ymd(1582, 10, 4);
+        assert_eq!(date.ceil_to_week(WeekDay::Friday).to_string(), "1582-10-15");
+        assert_eq!(date.ceil_to_week(WeekDay::Monday).to_string(), "1582-10-18");
+    }
+
+    #[test]
+    fn test_add_months() {
+        let date = CalendarDate::new(2000, 1, dec!(31));
+        assert_eq!(date.add_months(1), CalendarDate::new(2000, 2, dec!(29)));
+        assert_eq!(date.add_months(13), CalendarDate::new(2001, 2, dec!(28)));
+        assert_eq!(date.add_months(-2), CalendarDate::new(1999, 11, dec!(30)));
+        assert_eq!(date.add_months(-12), CalendarDate::new(1999, 1, dec!(31)));
+
+        // Julian leap year February
+        let date = CalendarDate::new(1500, 1, dec!(31.25));
+        assert_eq!(date.add_months(1), CalendarDate::new(1500, 2, dec!(29.25)));
+
+        // Crossing the 1582 gap
+        let date = CalendarDate::new(1582, 9, dec!(4));
+        assert_eq!(date.add_months(1), CalendarDate::new(1582, 10, dec!(4)));
+
+        let date = CalendarDate::new(1582, 9, dec!(10));
+        assert_eq!(date.add_months(1), CalendarDate::new(1582, 10, dec!(20)));
+
+        let date = CalendarDate::new(-1, 2, dec!(1));
+        assert_eq!(date.add_months(-2), CalendarDate::new(-2, 12, dec!(1)));
+    }
+
+    #[test]
+    fn test_checked_add_months() {
+        let date = CalendarDate::new(2000, 1, dec!(31));
+        assert_eq!(date.checked_add_months(1), Some(CalendarDate::new(2000, 2, dec!(29))));
+        assert_eq!(date.checked_add_months(i32::MAX), Some(CalendarDate::new(178958970, 8, dec!(31))));
+        assert_eq!(date.checked_add_months(i32::MIN), Some(CalendarDate::new(-178954971, 5, dec!(31))));
+
+        // The year overflows an i32
+        assert_eq!(CalendarDate::new(i32::MAX, 12, dec!(1)).checked_add_months(1), None);
+        assert_eq!(CalendarDate::new(i32::MIN, 1, dec!(1)).checked_add_months(-1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_months_overflow() {
+        CalendarDate::new(i32::MAX, 12, dec!(1)).add_months(1);
+    }
+
+    #[test]
+    fn test_range() {
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new(2000, 2, dec!(27)),
+            CalendarDate::new(2000, 3, dec!(2)),
+        ).collect();
+
+        assert_eq!(dates, vec![
+            CalendarDate::new(2000, 2, dec!(27)),
+            CalendarDate::new(2000, 2, dec!(28)),
+            CalendarDate::new(2000, 2, dec!(29)),
+            CalendarDate::new(2000, 3, dec!(1)),
+        ]);
+
+        // 1582 October 5th through 14th do not exist
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new(1582, 10, dec!(3)),
+            CalendarDate::new(1582, 10, dec!(17)),
+        ).collect();
+
+        assert_eq!(dates, vec![
+            CalendarDate::new(1582, 10, dec!(3)),
+            CalendarDate::new(1582, 10, dec!(4)),
+            CalendarDate::new(1582, 10, dec!(15)),
+            CalendarDate::new(1582, 10, dec!(16)),
+        ]);
+
+        let start = CalendarDate::new(2000, 1, dec!(1));
+        assert_eq!(CalendarDate::range(start, start).count(), 0);
+
+        // The dates keep the calendar of the start of the range
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new_in(1582, 10, dec!(3), Calendar::Gregorian),
+            CalendarDate::new_in(1582, 10, dec!(6), Calendar::Gregorian),
+        ).collect();
+        assert_eq!(dates.len(), 3);
+        assert!(dates.iter().all(|date| date.get_calendar() == Calendar::Gregorian));
+        assert_eq!(dates[2].to_string(), "1582-10-05");
+
+        // The days before JulianDay 0 are skipped, but not the rest of the range
+        let dates: Vec<String> = CalendarDate::range(
+            CalendarDate::new(-4713, 12, dec!(30)),
+            CalendarDate::new(-4712, 1, dec!(4)),
+        ).map(|date| date.to_string()).collect();
+        assert_eq!(dates, vec!["-4712-01-02", "-4712-01-03"]);
+
+        let end = CalendarDate::new(-4713, 12, dec!(31));
+        assert_eq!(CalendarDate::range(CalendarDate::new(-4713, 12, dec!(1)), end).count(), 0);
+    }
+
+    #[test]
+    fn test_with_reform() {
+        // Great Britain went from 1752 September 2nd (Julian) directly to September 14th (Gregorian)
+        let last_julian = CalendarDate::from_ymd(1752, 9, 2).with_reform(JulianDay::BRITISH_REFORM);
+        let first_gregorian = CalendarDate::from_ymd(1752, 9, 14).with_reform(JulianDay::BRITISH_REFORM);
+        assert_eq!(last_julian.get_calendar(), Calendar::Julian);
+        assert_eq!(first_gregorian.get_calendar(), Calendar::Gregorian);
+        assert_eq!(JulianDay::from(first_gregorian), JulianDay::BRITISH_REFORM);
+        assert_eq!(first_gregorian - last_julian, DaySpan(Decimal::ONE));
+        assert_eq!(last_julian.succ().to_string(), "1752-09-14");
+        assert_eq!(first_gregorian.pred().to_string(), "1752-09-02");
+
+        // September 1752 loses 11 days
+        assert_eq!(CalendarDate::skipped_days(&last_julian, &first_gregorian), dec!(11));
+        let days = DateRange::new(last_julian.with_day(Decimal::ONE), CalendarDate::from_ymd(1752, 10, 1).with_reform(JulianDay::BRITISH_REFORM));
+        assert_eq!(days.len(), 19);
+        assert!(!CalendarDate::from_ymd(1752, 9, 3).with_reform(JulianDay::BRITISH_REFORM).is_valid());
+        assert!(!CalendarDate::from_ymd(1752, 9, 13).with_reform(JulianDay::BRITISH_REFORM).is_valid());
+
+        // 1700 was a leap year in Great Britain, but not under the 1582 reform
+        assert!(CalendarDate::from_ymd(1700, 2, 29).with_reform(JulianDay::BRITISH_REFORM).is_valid());
+        assert!(!CalendarDate::from_ymd(1700, 2, 29).is_valid());
+
+        // The 1582 dates are Julian, so ten days behind the default
+        let date = CalendarDate::from_ymd(1582, 10, 10).with_reform(JulianDay::BRITISH_REFORM);
+        assert!(date.is_valid());
+        assert_eq!(date, CalendarDate::from_ymd(1582, 10, 20));
+
+        let date = CalendarDate::from_julian_day_with_reform(JulianDay::new(dec!(2361221)), JulianDay::BRITISH_REFORM).unwrap();
+        assert_eq!((date.year(), date.month(), date.day(), date.get_calendar()), (1752, 9, dec!(2.5), Calendar::Julian));
+        assert_eq!(date.get_reform(), JulianDay::BRITISH_REFORM);
+        assert_eq!(CalendarDate::from_ymd(2000, 1, 1).get_reform(), JulianDay::GREGORIAN_REFORM);
+    }
+
+    #[test]
+    fn test_date_range() {
+        let range = DateRange::new(CalendarDate::from_ymd(2000, 2, 27), CalendarDate::from_ymd(2000, 3, 2));
+        assert_eq!(range.len(), 4);
+        assert!(!range.is_empty());
+
+        let mut days = Vec::new();
+        for date in range {
+            days.push(date.to_string());
+        }
+        assert_eq!(days, vec!["2000-02-27", "2000-02-28", "2000-02-29", "2000-03-01"]);
+
+        let days: Vec<String> = range.into_iter().rev().map(|d| d.to_string()).collect();
+        assert_eq!(days, vec!["2000-03-01", "2000-02-29", "2000-02-28", "2000-02-27"]);
+
+        // Both ends can be consumed from the same iterator
+        let mut iter = range.into_iter();
+        assert_eq!(iter.next(), Some(CalendarDate::from_ymd(2000, 2, 27)));
+        assert_eq!(iter.next_back(), Some(CalendarDate::from_ymd(2000, 3, 1)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(CalendarDate::from_ymd(2000, 2, 29)));
+        assert_eq!(iter.next(), Some(CalendarDate::from_ymd(2000, 2, 28)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // 1582 October 5th through 14th do not exist
+        let range = DateRange::new(CalendarDate::from_ymd(1582, 10, 1), CalendarDate::from_ymd(1582, 10, 20));
+        assert_eq!(range.len(), 9);
+        assert_eq!(range.into_iter().rev().nth(5), Some(CalendarDate::from_ymd(1582, 10, 4)));
+
+        // A fractional start keeps its time of day
+        let range = DateRange::new(CalendarDate::new(2000, 1, dec!(1.5)), CalendarDate::from_ymd(2000, 1, 3));
+        assert_eq!(range.len(), 2);
+        assert_eq!(range.into_iter().next_back(), Some(CalendarDate::new(2000, 1, dec!(2.5))));
+
+        let empty = DateRange::new(CalendarDate::from_ymd(2000, 1, 3), CalendarDate::from_ymd(2000, 1, 1));
+        assert!(empty.is_empty());
+        assert_eq!(empty.into_iter().next_back(), None);
+
+        // Only the days from JulianDay 0 onwards are counted, as the earlier ones cannot be yielded
+        let range = DateRange::new(CalendarDate::from_ymd(-4713, 12, 30), CalendarDate::from_ymd(-4712, 1, 3));
+        let mut iter = range.into_iter();
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(CalendarDate::from_ymd(-4712, 1, 2)));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let range = DateRange::new(CalendarDate::from_ymd(-4713, 12, 30), CalendarDate::from_ymd(-4712, 1, 5));
+        assert_eq!(range.len(), range.into_iter().count());
+        assert_eq!(range.into_iter().rev().count(), 3);
+
+        // Both directions keep the calendar of the start of the range
+        let range = DateRange::new(CalendarDate::new_in(1582, 10, dec!(3), Calendar::Gregorian), CalendarDate::new_in(1582, 10, dec!(6), Calendar::Gregorian));
+        assert_eq!(range.into_iter().next_back().unwrap().to_string(), "1582-10-05");
+    }
+
+    #[test]
+    fn test_range_rev_step_by_days() {
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new(1582, 10, dec!(1)),
+            CalendarDate::new(1582, 10, dec!(31)),
+        ).step_by_days(dec!(7)).rev().collect();
+
+        assert_eq!(dates, vec![
+            CalendarDate::new(1582, 10, dec!(25)),
+            CalendarDate::new(1582, 10, dec!(18)),
+            CalendarDate::new(1582, 10, dec!(1)),
+        ]);
+    }
+
+    #[test]
+    fn test_range_step_by_days() {
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new(1582, 10, dec!(1)),
+            CalendarDate::new(1582, 10, dec!(31)),
+        ).step_by_days(dec!(7)).collect();
+
+        assert_eq!(dates, vec![
+            CalendarDate::new(1582, 10, dec!(1)),
+            CalendarDate::new(1582, 10, dec!(18)),
+            CalendarDate::new(1582, 10, dec!(25)),
+        ]);
+
+        let dates: Vec<CalendarDate> = CalendarDate::range(
+            CalendarDate::new(2000, 1, dec!(1)),
+            CalendarDate::new(2000, 1, dec!(2)),
+        ).step_by_days(dec!(0.25)).collect();
+
+        assert_eq!(dates.len(), 4);
+        assert_eq!(dates[3], CalendarDate::new(2000, 1, dec!(1.75)));
+    }
+
+    #[test]
+    fn test_julian_day_at_midnight() {
+        // Example 7.a, 1957 October 4.81
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(date.julian_day_at_midnight().day, dec!(2436115.5));
+
+        // Late in the day must not round up to the next day
+        let date = CalendarDate::new(2000, 1, dec!(1.99));
+        assert_eq!(date.julian_day_at_midnight().day, dec!(2451544.5));
+        assert_eq!(CalendarDate::new(2000, 1, dec!(1)).julian_day_at_midnight().day, dec!(2451544.5));
+
+        let date = CalendarDate::new(333, 1, dec!(27.5));
+        assert_eq!(date.julian_day_at_midnight().day, dec!(1842712.5));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(CalendarDate::new(1957, 10, dec!(4.81)).is_valid());
+        assert!(CalendarDate::new(2000, 2, dec!(29.5)).is_valid());
+        assert!(!CalendarDate::new(1900, 2, dec!(29)).is_valid());
+        assert!(!CalendarDate::new(2000, 0, dec!(1)).is_valid());
+        assert!(!CalendarDate::new(2000, 13, dec!(1)).is_valid());
+        assert!(!CalendarDate::new(2000, 1, dec!(0.5)).is_valid());
+        assert!(!CalendarDate::new(2000, 4, dec!(31)).is_valid());
+
+        assert!(!CalendarDate::new(1582, 10, dec!(10)).is_valid());
+        assert!(CalendarDate::new(1582, 10, dec!(4.99)).is_valid());
+        assert!(CalendarDate::new_in(1582, 10, dec!(10), Calendar::Gregorian).is_valid());
+
+        // The proleptic Gregorian calendar has no February 29th in 1500
+        assert!(CalendarDate::new(1500, 2, dec!(29)).is_valid());
+        assert!(!CalendarDate::new_in(1500, 2, dec!(29), Calendar::Gregorian).is_valid());
+    }
+
+    #[test]
+    fn test_from_ymd() {
+        assert_eq!(CalendarDate::from_ymd(1957, 10, 4), CalendarDate::new(1957, 10, dec!(4)));
+        assert_eq!(JulianDay::from(CalendarDate::from_ymd(2000, 1, 1)).day, dec!(2451544.5));
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 15).day_of_the_week(), WeekDay::Friday);
+    }
+
+    #[test]
+    fn test_same_day() {
+        let morning = CalendarDate::new(1957, 10, dec!(4.1));
+        let evening = CalendarDate::new(1957, 10, dec!(4.9));
+        assert_ne!(morning, evening);
+        assert!(morning.same_day(&evening));
+        assert!(!morning.same_day(&CalendarDate::new(1957, 10, dec!(5))));
+
+        // 1582 October 4th in the Julian calendar is October 14th in the proleptic Gregorian calendar
+        let julian = CalendarDate::new(1582, 10, dec!(4.25));
+        assert!(julian.same_day(&CalendarDate::new_in(1582, 10, dec!(14.75), Calendar::Gregorian)));
+    }
+
+    #[test]
+    fn test_time_of_day() {
+        let date = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(date.time_of_day(), (19, 26, dec!(24)));
+
+        let date = CalendarDate::new(2000, 1, dec!(1.5));
+        assert_eq!(date.time_of_day(), (12, 0, dec!(0)));
+
+        let date = CalendarDate::new(2000, 1, dec!(1));
+        assert_eq!(date.time_of_day(), (0, 0, dec!(0)));
+
+        let date = CalendarDate::with_time(1957, 10, 4, 19, 26, dec!(24));
+        assert_eq!(date, CalendarDate::new(1957, 10, dec!(4.81)));
+        assert_eq!(date.time_of_day(), (19, 26, dec!(24)));
+
+        let date = CalendarDate::with_time(2024, 3, 9, 23, 59, dec!(59.5));
+        assert_eq!(date.time_of_day(), (23, 59, dec!(59.5)));
+    }
+
+    #[test]
+    fn test_builder() {
+        let date = CalendarDate::builder().year(1957).month(10).day(dec!(4.81)).build();
+        assert_eq!(date, Ok(CalendarDate::new(1957, 10, dec!(4.81))));
+
+        let date = CalendarDate::builder().day(dec!(29)).month(2).year(2000).build();
+        assert_eq!(date, Ok(CalendarDate::new(2000, 2, dec!(29))));
+
+        let date = CalendarDate::builder().year(1900).month(2).day(dec!(29)).build();
+        assert_eq!(date, Err(DateValidationError::DayOutOfRange));
+
+        let date = CalendarDate::builder().year(1582).month(10).day(dec!(10)).build();
+        assert_eq!(date, Err(DateValidationError::DroppedDay));
+
+        let date = CalendarDate::builder().year(1957).month(10).build();
+        assert_eq!(date, Err(DateValidationError::MissingField));
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(CalendarDate::try_from("1957-10-04").unwrap(), CalendarDate::from_ymd(1957, 10, 4));
+        assert_eq!(CalendarDate::try_from("1957-10-04T12:00").unwrap(), CalendarDate::new(1957, 10, dec!(4.5)));
+        assert_eq!(CalendarDate::try_from("1957/10/04").unwrap(), CalendarDate::from_ymd(1957, 10, 4));
+        assert_eq!(CalendarDate::try_from("04-10-1957").unwrap(), CalendarDate::from_ymd(1957, 10, 4));
+        assert_eq!(CalendarDate::try_from("1957-277").unwrap(), CalendarDate::from_ymd(1957, 10, 4));
+        assert_eq!(CalendarDate::try_from("2000-366").unwrap(), CalendarDate::from_ymd(2000, 12, 31));
+
+        // Recognized formats still reject dates which do not exist
+        assert!(matches!(CalendarDate::try_from("1957/13/04"), Err(ParseError::MonthOutOfRange)));
+        assert!(matches!(CalendarDate::try_from("31-04-1957"), Err(ParseError::DayOutOfRange)));
+        assert!(matches!(CalendarDate::try_from("1957-366"), Err(ParseError::DayOutOfRange)));
+        assert!(matches!(CalendarDate::try_from("1582/10/10"), Err(ParseError::DayOutOfRange)));
+        assert!(matches!(CalendarDate::try_from("1957/1O/04"), Err(ParseError::Malformed)));
+
+        let err = CalendarDate::try_from("October 4th, 1957").unwrap_err();
+        assert!(matches!(err, ParseError::UnrecognizedFormat));
+        assert_eq!(err.to_string(), "expected a date of the form YYYY-MM-DD, YYYY/MM/DD, DD-MM-YYYY or YYYY-DDD");
+        assert!(matches!(CalendarDate::try_from("1957.10.04"), Err(ParseError::UnrecognizedFormat)));
+        assert!(matches!(CalendarDate::try_from(""), Err(ParseError::UnrecognizedFormat)));
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04"), Ok(CalendarDate::new(1957, 10, dec!(4))));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04T19:26:24"), Ok(CalendarDate::new(1957, 10, dec!(4.81))));
+        assert_eq!(CalendarDate::parse_iso8601("2000-01-01T12:00"), Ok(CalendarDate::new(2000, 1, dec!(1.5))));
+        assert_eq!(CalendarDate::parse_iso8601("-0123-12-31"), Ok(CalendarDate::new(-123, 12, dec!(31))));
+        assert_eq!(CalendarDate::parse_iso8601("2000-02-29"), Ok(CalendarDate::new(2000, 2, dec!(29))));
+
+        assert_eq!(CalendarDate::parse_iso8601("1957/10/04"), Err(ParseError::Malformed));
+        assert_eq!(CalendarDate::parse_iso8601("1957-1-04"), Err(ParseError::Malformed));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04T19"), Err(ParseError::Malformed));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04-01"), Err(ParseError::Malformed));
+        assert_eq!(CalendarDate::parse_iso8601(""), Err(ParseError::Malformed));
+        assert_eq!(CalendarDate::parse_iso8601("1957-13-04"), Err(ParseError::MonthOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1957-00-04"), Err(ParseError::MonthOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1957-04-31"), Err(ParseError::DayOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1900-02-29"), Err(ParseError::DayOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-00"), Err(ParseError::DayOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1582-10-10"), Err(ParseError::DayOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04T24:00:00"), Err(ParseError::TimeOutOfRange));
+        assert_eq!(CalendarDate::parse_iso8601("1957-10-04T12:60"), Err(ParseError::TimeOutOfRange));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(CalendarDate::new(1957, 10, dec!(4.81)).to_string(), "1957-10-04");
+        assert_eq!(CalendarDate::new(837, 4, dec!(10.3)).to_string(), "0837-04-10");
+        assert_eq!(CalendarDate::new(-123, 12, dec!(31)).to_string(), "-0123-12-31");
+        assert_eq!(CalendarDate::new(-4712, 1, dec!(1.5)).to_string(), "-4712-01-01");
+    }
+
+    #[test]
+    fn test_format() {
+        let date = CalendarDate::new(1978, 11, dec!(14));
+        assert_eq!(date.format("%d/%m/%Y"), "14/11/1978");
+        assert_eq!(date.format("%Y-%j"), "1978-318");
+        assert_eq!(date.format("100%% on %Y"), "100% on 1978");
+        assert_eq!(date.format("%q %"), "%q %");
+
+        let date = CalendarDate::new(-44, 3, dec!(15));
+        assert_eq!(date.format("%Y %m %d"), "-0044 03 15");
+    }
+
+    #[test]
+    fn test_day_of_the_week_known_dates() {
+        let known = [
+            ((-4712, 1, 1), WeekDay::Monday),
+            ((1, 1, 1), WeekDay::Saturday),
+            ((1066, 10, 14), WeekDay::Saturday),
+            ((1492, 10, 12), WeekDay::Friday),
+            ((1582, 10, 4), WeekDay::Thursday),
+            ((1582, 10, 15), WeekDay::Friday),
+            ((1600, 3, 1), WeekDay::Wednesday),
+            ((1776, 7, 4), WeekDay::Thursday),
+            ((1815, 6, 18), WeekDay::Sunday),
+            ((1900, 1, 1), WeekDay::Monday),
+            ((1954, 6, 30), WeekDay::Wednesday),
+            ((1969, 7, 20), WeekDay::Sunday),
+            ((2000, 2, 29), WeekDay::Tuesday),
+            ((2100, 12, 31), WeekDay::Friday),
+        ];
+
+        // The weekday is that of the civil day, from midnight until just before the next one
+        for ((y, m, d), weekday) in known {
+            for fraction in [dec!(0), dec!(0.5), dec!(0.81), dec!(0.99), dec!(0.999999)] {
+                let date = CalendarDate::new(y, m, Decimal::from(d) + fraction);
+                assert_eq!(date.day_of_the_week(), weekday, "{} + {}", date, fraction);
+            }
+        }
+    }
+
+    #[test]
+    fn test_day_of_the_week() {
+        let date = CalendarDate::new(1954, 6, dec!(30));
+        assert_eq!(date.day_of_the_week(), WeekDay::Wednesday);
+
+        // Fractional days belong to the civil day they fall in, right up to midnight
+        for d in [dec!(30.5), dec!(30.51), dec!(30.999999)] {
+            assert_eq!(CalendarDate::new(1954, 6, d).day_of_the_week(), WeekDay::Wednesday);
+        }
+        let date = CalendarDate::new(2000, 1, dec!(31.9));
+        assert_eq!(date.day_of_the_week(), WeekDay::Monday);
+
+        // The reduction stays within 0..=6 right down to JulianDay 0
+        let date = CalendarDate::new(-4712, 1, dec!(1));
+        assert_eq!(date.day_of_the_week(), WeekDay::Monday);
+        let date = CalendarDate::new(-4712, 1, dec!(7));
+        assert_eq!(date.day_of_the_week(), WeekDay::Sunday);
+
+        // 1582 October 4th (Julian) was followed directly by October 15th (Gregorian)
+        let date = CalendarDate::new(1582, 10, dec!(4));
+        assert_eq!(date.day_of_the_week(), WeekDay::Thursday);
+
+        let date = CalendarDate::new(1582, 10, dec!(15));
+        assert_eq!(date.day_of_the_week(), WeekDay::Friday);
+
+        // Repeated calls must be cheap and side-effect free
+        let days: Vec<WeekDay> = (1..=7)
+            .map(|d| CalendarDate::new(2000, 1, Decimal::from(d)).day_of_the_week())
+            .collect();
+
+        assert_eq!(days, vec![
+            WeekDay::Saturday,
+            WeekDay::Sunday,
+            WeekDay::Monday,
+            WeekDay::Tuesday,
+            WeekDay::Wednesday,
+            WeekDay::Thursday,
+            WeekDay::Friday,
+        ]);
+    }
+
+    #[test]
+    fn test_days_until() {
+        // 1954 June 30th was a Wednesday
+        let date = CalendarDate::new(1954, 6, dec!(30));
+        assert_eq!(date.days_until(WeekDay::Wednesday), 0);
+        assert_eq!(date.days_until(WeekDay::Thursday), 1);
+        assert_eq!(date.days_until(WeekDay::Saturday), 3);
+        assert_eq!(date.days_until(WeekDay::Sunday), 4);
+        assert_eq!(date.days_until(WeekDay::Tuesday), 6);
+
+        // Across the 1582 reform, Thursday October 4th was followed by Friday October 15th
+        let date = CalendarDate::new(1582, 10, dec!(4));
+        let n = date.days_until(WeekDay::Monday);
+        assert_eq!(n, 4);
+        assert_eq!(date.add_days(Decimal::from(n)), CalendarDate::new(1582, 10, dec!(18)));
+    }
+
+    #[test]
+    fn test_quarter() {
+        let quarters = [(1, 1), (3, 1), (4, 2), (6, 2), (7, 3), (9, 3), (10, 4), (12, 4)];
+
+        for (m, q) in quarters {
+            assert_eq!(CalendarDate::new(2000, m, dec!(1)).quarter(), q);
+        }
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        // 2000 January 1st was a Saturday
+        assert!(CalendarDate::new(2000, 1, dec!(1)).is_weekend());
+        assert!(CalendarDate::new(2000, 1, dec!(2)).is_weekend());
+        assert!(!CalendarDate::new(2000, 1, dec!(3)).is_weekend());
+        assert!(!CalendarDate::new(2000, 1, dec!(7)).is_weekend());
+    }
+
+    #[test]
+    fn test_day_of_the_year() {
+        let date1 = CalendarDate::new(1978, 11, dec!(14));
+        let date2 = CalendarDate::new(1988, 4, dec!(22));
+
+        assert_eq!(date1.day_of_the_year(), 318);
+        assert_eq!(date2.day_of_the_year(), 113);
+
+        // The dropped days of 1582 are not counted
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 4).day_of_the_year(), 277);
+        assert_eq!(CalendarDate::from_ymd(1582, 10, 15).day_of_the_year(), 278);
+        assert_eq!(CalendarDate::from_ymd(1582, 12, 31).day_of_the_year(), 355);
+        assert_eq!(CalendarDate::from_ymd(1582, 12, 31).with_calendar(Calendar::Gregorian).day_of_the_year(), 365);
+    }
+
+    #[test]
+    fn test_from_day_of_year() {
+        let date = CalendarDate::from_day_of_year(1978, 318).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1978, 11, dec!(14)));
+
+        let date = CalendarDate::from_day_of_year(1988, 113).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1988, 4, dec!(22)));
+
+        for year in [1900, 1988, 2000, 2023, 2024] {
+            let days = if CalendarDate::new(year, 1, dec!(1)).leap_year() { 366 } else { 365 };
+            for doy in 1..=days {
+                assert_eq!(CalendarDate::from_day_of_year(year, doy).unwrap().day_of_the_year(), doy);
+            }
+        }
+
+        let date = CalendarDate::from_day_of_year(2024, 366).unwrap();
+        assert_eq!((date.month(), date.day()), (12, dec!(31)));
+        assert_eq!(CalendarDate::from_day_of_year(2023, 366), Err(DateValidationError::DayOfYearOutOfRange));
+        assert_eq!(CalendarDate::from_day_of_year(2024, 0), Err(DateValidationError::DayOfYearOutOfRange));
+
+        // 1582 October 4th is directly followed by October 15th
+        assert_eq!(CalendarDate::from_day_of_year(1582, 277).unwrap().to_string(), "1582-10-04");
+        assert_eq!(CalendarDate::from_day_of_year(1582, 278).unwrap().to_string(), "1582-10-15");
+        assert_eq!(CalendarDate::from_day_of_year(1582, 355).unwrap().to_string(), "1582-12-31");
+        assert_eq!(CalendarDate::from_day_of_year(1582, 356), Err(DateValidationError::DayOfYearOutOfRange));
+        for doy in 1..=355 {
+            let date = CalendarDate::from_day_of_year(1582, doy).unwrap();
+            assert!(date.is_valid());
+            assert_eq!(date.day_of_the_year(), doy);
+        }
+
+        // Every day from 1582 October 1st through December 31st round-trips
+        let mut date = CalendarDate::from_ymd(1582, 10, 1);
+        while date.year() == 1582 {
+            assert_eq!(CalendarDate::from_day_of_year(1582, date.day_of_the_year()), Ok(date));
+            date = date.succ();
+        }
+    }
+
+    #[test]
+    fn test_iso_week() {
+        assert_eq!(CalendarDate::new(2005, 1, dec!(1)).iso_week(), (2004, 53));
+        assert_eq!(CalendarDate::new(2005, 1, dec!(2)).iso_week(), (2004, 53));
+        assert_eq!(CalendarDate::new(2005, 1, dec!(3)).iso_week(), (2005, 1));
+        assert_eq!(CalendarDate::new(2007, 1, dec!(1)).iso_week(), (2007, 1));
+        assert_eq!(CalendarDate::new(2007, 12, dec!(30)).iso_week(), (2007, 52));
+        assert_eq!(CalendarDate::new(2007, 12, dec!(31)).iso_week(), (2008, 1));
+        assert_eq!(CalendarDate::new(2008, 12, dec!(29)).iso_week(), (2009, 1));
+        assert_eq!(CalendarDate::new(2009, 12, dec!(31)).iso_week(), (2009, 53));
+        assert_eq!(CalendarDate::new(2010, 1, dec!(3)).iso_week(), (2009, 53));
+        assert_eq!(CalendarDate::new(2024, 6, dec!(15)).iso_week(), (2024, 24));
+    }
+
+    #[test]
+    fn test_leap_year() {
+        // Gregorian century years are only leap years when divisible by 400
+        assert!(!CalendarDate::new(1700, 3, dec!(1)).leap_year());
+        assert!(!CalendarDate::new(1800, 3, dec!(1)).leap_year());
+        assert!(!CalendarDate::new(1900, 3, dec!(1)).leap_year());
+        assert!(CalendarDate::new(2000, 3, dec!(1)).leap_year());
+
+        // Every fourth Julian year is a leap year, centuries included
+        assert!(CalendarDate::new(300, 3, dec!(1)).leap_year());
+        assert!(!CalendarDate::new(301, 3, dec!(1)).leap_year());
+
+        assert_eq!(CalendarDate::new(1900, 3, dec!(1)).day_of_the_year(), 60);
+        assert_eq!(CalendarDate::new(2000, 3, dec!(1)).day_of_the_year(), 61);
     }
 }
\ No newline at end of file
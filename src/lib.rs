@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod angle;
+#[cfg(feature = "chrono")]
+pub mod chrono_compat;
+pub mod collection;
+pub mod coordinates;
+pub mod datetime;
+pub mod dynamical;
+pub mod easter;
+pub mod eclipse;
+pub mod holidays;
+pub mod interpolation;
+pub mod julian;
+pub mod moon;
+pub mod nutation;
+pub mod planets;
+pub mod precession;
+pub mod recurrence;
+pub mod rise_set;
+pub mod seasons;
+pub mod sidereal;
+pub mod sun;
+#[cfg(feature = "time")]
+pub mod time_compat;
@@ -1,7 +1,5 @@
 use macroquad::prelude::*;
 
-mod julian;
-
 #[macroquad::main("MyGame")]
 async fn main() {
     loop {
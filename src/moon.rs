@@ -0,0 +1,593 @@
+use alloc::vec::Vec;
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use rust_decimal::prelude::*;
+
+use crate::angle::{acos_deg, asin_deg, atan2_deg, cos_deg, normalize_degrees, sin_deg};
+use crate::dynamical::TimeScale;
+use crate::julian::{CalendarDate, JulianDay};
+use crate::nutation::{nutation, true_obliquity};
+use crate::planets::{Planet, planet_heliocentric};
+use crate::sun::sun_apparent_equatorial;
+
+/// A periodic term ``coefficient * E^e * sin(m' M' + m M + f F)``.
+type PhaseTerm = (Decimal, u8, i8, i8, i8);
+
+const NEW_MOON_TERMS: [PhaseTerm; 24] = [
+    (dec!(-0.40720), 0, 1, 0, 0),
+    (dec!(0.17241), 1, 0, 1, 0),
+    (dec!(0.01608), 0, 2, 0, 0),
+    (dec!(0.01039), 0, 0, 0, 2),
+    (dec!(0.00739), 1, 1, -1, 0),
+    (dec!(-0.00514), 1, 1, 1, 0),
+    (dec!(0.00208), 2, 0, 2, 0),
+    (dec!(-0.00111), 0, 1, 0, -2),
+    (dec!(-0.00057), 0, 1, 0, 2),
+    (dec!(0.00056), 1, 2, 1, 0),
+    (dec!(-0.00042), 0, 3, 0, 0),
+    (dec!(0.00042), 1, 0, 1, 2),
+    (dec!(0.00038), 1, 0, 1, -2),
+    (dec!(-0.00024), 1, 2, -1, 0),
+    (dec!(-0.00007), 0, 1, 2, 0),
+    (dec!(0.00004), 0, 2, 0, -2),
+    (dec!(0.00004), 0, 0, 3, 0),
+    (dec!(0.00003), 0, 1, 1, -2),
+    (dec!(0.00003), 0, 2, 0, 2),
+    (dec!(-0.00003), 0, 1, 1, 2),
+    (dec!(0.00003), 0, 1, -1, 2),
+    (dec!(-0.00002), 0, 1, -1, -2),
+    (dec!(-0.00002), 0, 3, 1, 0),
+    (dec!(0.00002), 0, 4, 0, 0),
+];
+
+const FULL_MOON_TERMS: [PhaseTerm; 24] = [
+    (dec!(-0.40614), 0, 1, 0, 0),
+    (dec!(0.17302), 1, 0, 1, 0),
+    (dec!(0.01614), 0, 2, 0, 0),
+    (dec!(0.01043), 0, 0, 0, 2),
+    (dec!(0.00734), 1, 1, -1, 0),
+    (dec!(-0.00515), 1, 1, 1, 0),
+    (dec!(0.00209), 2, 0, 2, 0),
+    (dec!(-0.00111), 0, 1, 0, -2),
+    (dec!(-0.00057), 0, 1, 0, 2),
+    (dec!(0.00056), 1, 2, 1, 0),
+    (dec!(-0.00042), 0, 3, 0, 0),
+    (dec!(0.00042), 1, 0, 1, 2),
+    (dec!(0.00038), 1, 0, 1, -2),
+    (dec!(-0.00024), 1, 2, -1, 0),
+    (dec!(-0.00007), 0, 1, 2, 0),
+    (dec!(0.00004), 0, 2, 0, -2),
+    (dec!(0.00004), 0, 0, 3, 0),
+    (dec!(0.00003), 0, 1, 1, -2),
+    (dec!(0.00003), 0, 2, 0, 2),
+    (dec!(-0.00003), 0, 1, 1, 2),
+    (dec!(0.00003), 0, 1, -1, 2),
+    (dec!(-0.00002), 0, 1, -1, -2),
+    (dec!(-0.00002), 0, 3, 1, 0),
+    (dec!(0.00002), 0, 4, 0, 0),
+];
+
+const QUARTER_TERMS: [PhaseTerm; 24] = [
+    (dec!(-0.62801), 0, 1, 0, 0),
+    (dec!(0.17172), 1, 0, 1, 0),
+    (dec!(-0.01183), 1, 1, 1, 0),
+    (dec!(0.00862), 0, 2, 0, 0),
+    (dec!(0.00804), 0, 0, 0, 2),
+    (dec!(0.00454), 1, 1, -1, 0),
+    (dec!(0.00204), 2, 0, 2, 0),
+    (dec!(-0.00180), 0, 1, 0, -2),
+    (dec!(-0.00070), 0, 1, 0, 2),
+    (dec!(-0.00040), 0, 3, 0, 0),
+    (dec!(-0.00034), 1, 2, -1, 0),
+    (dec!(0.00032), 1, 0, 1, 2),
+    (dec!(0.00032), 1, 0, 1, -2),
+    (dec!(-0.00028), 2, 1, 2, 0),
+    (dec!(0.00027), 1, 2, 1, 0),
+    (dec!(-0.00005), 0, 1, -1, -2),
+    (dec!(0.00004), 0, 2, 0, 2),
+    (dec!(-0.00004), 0, 1, 1, 2),
+    (dec!(0.00004), 0, 1, -2, 0),
+    (dec!(0.00003), 0, 1, 1, -2),
+    (dec!(0.00003), 0, 0, 3, 0),
+    (dec!(0.00002), 0, 2, 0, -2),
+    (dec!(0.00002), 0, 1, -1, 2),
+    (dec!(-0.00002), 0, 3, 1, 0),
+];
+
+/// Additional corrections ``(coefficient, A0, A1)`` for all phases, where ``A = A0 + A1 k``.
+const PLANETARY_TERMS: [(Decimal, Decimal, Decimal); 13] = [
+    (dec!(0.000165), dec!(251.88), dec!(0.016321)),
+    (dec!(0.000164), dec!(251.83), dec!(26.651886)),
+    (dec!(0.000126), dec!(349.42), dec!(36.412478)),
+    (dec!(0.000110), dec!(84.66), dec!(18.206239)),
+    (dec!(0.000062), dec!(141.74), dec!(53.303771)),
+    (dec!(0.000060), dec!(207.14), dec!(2.453732)),
+    (dec!(0.000056), dec!(154.84), dec!(7.306860)),
+    (dec!(0.000047), dec!(34.52), dec!(27.261239)),
+    (dec!(0.000042), dec!(207.19), dec!(0.121824)),
+    (dec!(0.000040), dec!(291.34), dec!(1.844379)),
+    (dec!(0.000037), dec!(161.72), dec!(24.198154)),
+    (dec!(0.000035), dec!(239.56), dec!(25.513099)),
+    (dec!(0.000023), dec!(331.55), dec!(3.592518)),
+];
+
+/// A periodic term of the Moon's longitude and distance: the multiples of ``D``, ``M``, ``M'`` and ``F``,
+/// followed by the sine coefficient of ``Σl`` in 0.000001 degree and the cosine coefficient of ``Σr`` in 0.001 km.
+type LongitudeDistanceTerm = (i8, i8, i8, i8, Decimal, Decimal);
+
+const LONGITUDE_DISTANCE_TERMS: [LongitudeDistanceTerm; 60] = [
+    (0, 0, 1, 0, dec!(6288774), dec!(-20905355)),
+    (2, 0, -1, 0, dec!(1274027), dec!(-3699111)),
+    (2, 0, 0, 0, dec!(658314), dec!(-2955968)),
+    (0, 0, 2, 0, dec!(213618), dec!(-569925)),
+    (0, 1, 0, 0, dec!(-185116), dec!(48888)),
+    (0, 0, 0, 2, dec!(-114332), dec!(-3149)),
+    (2, 0, -2, 0, dec!(58793), dec!(246158)),
+    (2, -1, -1, 0, dec!(57066), dec!(-152138)),
+    (2, 0, 1, 0, dec!(53322), dec!(-170733)),
+    (2, -1, 0, 0, dec!(45758), dec!(-204586)),
+    (0, 1, -1, 0, dec!(-40923), dec!(-129620)),
+    (1, 0, 0, 0, dec!(-34720), dec!(108743)),
+    (0, 1, 1, 0, dec!(-30383), dec!(104755)),
+    (2, 0, 0, -2, dec!(15327), dec!(10321)),
+    (0, 0, 1, 2, dec!(-12528), dec!(0)),
+    (0, 0, 1, -2, dec!(10980), dec!(79661)),
+    (4, 0, -1, 0, dec!(10675), dec!(-34782)),
+    (0, 0, 3, 0, dec!(10034), dec!(-23210)),
+    (4, 0, -2, 0, dec!(8548), dec!(-21636)),
+    (2, 1, -1, 0, dec!(-7888), dec!(24208)),
+    (2, 1, 0, 0, dec!(-6766), dec!(30824)),
+    (1, 0, -1, 0, dec!(-5163), dec!(-8379)),
+    (1, 1, 0, 0, dec!(4987), dec!(-16675)),
+    (2, -1, 1, 0, dec!(4036), dec!(-12831)),
+    (2, 0, 2, 0, dec!(3994), dec!(-10445)),
+    (4, 0, 0, 0, dec!(3861), dec!(-11650)),
+    (2, 0, -3, 0, dec!(3665), dec!(14403)),
+    (0, 1, -2, 0, dec!(-2689), dec!(-7003)),
+    (2, 0, -1, 2, dec!(-2602), dec!(0)),
+    (2, -1, -2, 0, dec!(2390), dec!(10056)),
+    (1, 0, 1, 0, dec!(-2348), dec!(6322)),
+    (2, -2, 0, 0, dec!(2236), dec!(-9884)),
+    (0, 1, 2, 0, dec!(-2120), dec!(5751)),
+    (0, 2, 0, 0, dec!(-2069), dec!(0)),
+    (2, -2, -1, 0, dec!(2048), dec!(-4950)),
+    (2, 0, 1, -2, dec!(-1773), dec!(4130)),
+    (2, 0, 0, 2, dec!(-1595), dec!(0)),
+    (4, -1, -1, 0, dec!(1215), dec!(-3958)),
+    (0, 0, 2, 2, dec!(-1110), dec!(0)),
+    (3, 0, -1, 0, dec!(-892), dec!(3258)),
+    (2, 1, 1, 0, dec!(-810), dec!(2616)),
+    (4, -1, -2, 0, dec!(759), dec!(-1897)),
+    (0, 2, -1, 0, dec!(-713), dec!(-2117)),
+    (2, 2, -1, 0, dec!(-700), dec!(2354)),
+    (2, 1, -2, 0, dec!(691), dec!(0)),
+    (2, -1, 0, -2, dec!(596), dec!(0)),
+    (4, 0, 1, 0, dec!(549), dec!(-1423)),
+    (0, 0, 4, 0, dec!(537), dec!(-1117)),
+    (4, -1, 0, 0, dec!(520), dec!(-1571)),
+    (1, 0, -2, 0, dec!(-487), dec!(-1739)),
+    (2, 1, 0, -2, dec!(-399), dec!(0)),
+    (0, 0, 2, -2, dec!(-381), dec!(-4421)),
+    (1, 1, 1, 0, dec!(351), dec!(0)),
+    (3, 0, -2, 0, dec!(-340), dec!(0)),
+    (4, 0, -3, 0, dec!(330), dec!(0)),
+    (2, -1, 2, 0, dec!(327), dec!(0)),
+    (0, 2, 1, 0, dec!(-323), dec!(1165)),
+    (1, 1, -1, 0, dec!(299), dec!(0)),
+    (2, 0, 3, 0, dec!(294), dec!(0)),
+    (2, 0, -1, -2, dec!(0), dec!(8752)),
+];
+
+/// A periodic term of the Moon's latitude: the multiples of ``D``, ``M``, ``M'`` and ``F``,
+/// followed by the sine coefficient of ``Σb`` in 0.000001 degree.
+type LatitudeTerm = (i8, i8, i8, i8, Decimal);
+
+const LATITUDE_TERMS: [LatitudeTerm; 60] = [
+    (0, 0, 0, 1, dec!(5128122)),
+    (0, 0, 1, 1, dec!(280602)),
+    (0, 0, 1, -1, dec!(277693)),
+    (2, 0, 0, -1, dec!(173237)),
+    (2, 0, -1, 1, dec!(55413)),
+    (2, 0, -1, -1, dec!(46271)),
+    (2, 0, 0, 1, dec!(32573)),
+    (0, 0, 2, 1, dec!(17198)),
+    (2, 0, 1, -1, dec!(9266)),
+    (0, 0, 2, -1, dec!(8822)),
+    (2, -1, 0, -1, dec!(8216)),
+    (2, 0, -2, -1, dec!(4324)),
+    (2, 0, 1, 1, dec!(4200)),
+    (2, 1, 0, -1, dec!(-3359)),
+    (2, -1, -1, 1, dec!(2463)),
+    (2, -1, 0, 1, dec!(2211)),
+    (2, -1, -1, -1, dec!(2065)),
+    (0, 1, -1, -1, dec!(-1870)),
+    (4, 0, -1, -1, dec!(1828)),
+    (0, 1, 0, 1, dec!(-1794)),
+    (0, 0, 0, 3, dec!(-1749)),
+    (0, 1, -1, 1, dec!(-1565)),
+    (1, 0, 0, 1, dec!(-1491)),
+    (0, 1, 1, 1, dec!(-1475)),
+    (0, 1, 1, -1, dec!(-1410)),
+    (0, 1, 0, -1, dec!(-1344)),
+    (1, 0, 0, -1, dec!(-1335)),
+    (0, 0, 3, 1, dec!(1107)),
+    (4, 0, 0, -1, dec!(1021)),
+    (4, 0, -1, 1, dec!(833)),
+    (0, 0, 1, -3, dec!(777)),
+    (4, 0, -2, 1, dec!(671)),
+    (2, 0, 0, -3, dec!(607)),
+    (2, 0, 2, -1, dec!(596)),
+    (2, -1, 1, -1, dec!(491)),
+    (2, 0, -2, 1, dec!(-451)),
+    (0, 0, 3, -1, dec!(439)),
+    (2, 0, 2, 1, dec!(422)),
+    (2, 0, -3, -1, dec!(421)),
+    (2, 1, -1, 1, dec!(-366)),
+    (2, 1, 0, 1, dec!(-351)),
+    (4, 0, 0, 1, dec!(331)),
+    (2, -1, 1, 1, dec!(315)),
+    (2, -2, 0, -1, dec!(302)),
+    (0, 0, 1, 3, dec!(-283)),
+    (2, 1, 1, -1, dec!(-229)),
+    (1, 1, 0, -1, dec!(223)),
+    (1, 1, 0, 1, dec!(223)),
+    (0, 1, -2, -1, dec!(-220)),
+    (2, 1, -1, -1, dec!(-220)),
+    (1, 0, 1, 1, dec!(-185)),
+    (2, -1, -2, -1, dec!(181)),
+    (0, 1, 2, 1, dec!(-177)),
+    (4, 0, -2, -1, dec!(176)),
+    (4, -1, -1, -1, dec!(166)),
+    (1, 0, 1, -1, dec!(-164)),
+    (4, 0, 1, -1, dec!(132)),
+    (1, 0, -1, -1, dec!(-119)),
+    (4, -1, 0, -1, dec!(115)),
+    (2, -2, 0, 1, dec!(107)),
+];
+
+/// The astronomical unit, in kilometers.
+const ASTRONOMICAL_UNIT: Decimal = dec!(149597870);
+
+/// The mean length of a synodic month, in days.
+pub(crate) const SYNODIC_MONTH: Decimal = dec!(29.530588861);
+
+/// The instant of the mean New Moon of 2000 January 6th, where ``k`` is 0, in Dynamical Time (TD).
+const MEAN_NEW_MOON_EPOCH: Decimal = dec!(2451550.09766);
+
+/// Returns the lunation ``k`` of the last mean New Moon at or before ``jd``, as counted by ``moon_phase``.
+pub(crate) fn mean_lunation(jd: JulianDay) -> Decimal {
+    ((jd.day - MEAN_NEW_MOON_EPOCH) / SYNODIC_MONTH).floor()
+}
+
+/// The arguments of a phase of the Moon, all angles in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct PhaseArguments {
+    /// The eccentricity factor of the Earth's orbit.
+    pub e: Decimal,
+    /// The Sun's mean anomaly.
+    pub m: Decimal,
+    /// The Moon's mean anomaly.
+    pub mp: Decimal,
+    /// The Moon's argument of latitude.
+    pub f: Decimal,
+    /// The longitude of the ascending node of the lunar orbit.
+    pub omega: Decimal,
+}
+
+/// Returns the arguments of the phase ``k`` of the Moon, counted as in ``moon_phase``.
+/// 
+/// Taken from "Phases of the Moon"
+pub(crate) fn phase_arguments(k: Decimal) -> PhaseArguments {
+    let t = k / dec!(1236.85);
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    PhaseArguments {
+        e: Decimal::ONE - dec!(0.002516) * t - dec!(0.0000074) * t2,
+        m: dec!(2.5534) + dec!(29.10535670) * k - dec!(0.0000014) * t2 - dec!(0.00000011) * t3,
+        mp: dec!(201.5643) + dec!(385.81693528) * k + dec!(0.0107582) * t2 + dec!(0.00001238) * t3 - dec!(0.000000058) * t4,
+        f: dec!(160.7108) + dec!(390.67050284) * k - dec!(0.0016118) * t2 - dec!(0.00000227) * t3 + dec!(0.000000011) * t4,
+        omega: dec!(124.7746) - dec!(1.56375588) * k + dec!(0.0020672) * t2 + dec!(0.00000215) * t3,
+    }
+}
+
+/// Returns the instant of a phase of the Moon.
+/// 
+/// Taken from "Phases of the Moon"
+/// 
+/// ``k`` counts lunations since the new moon of 2000 January 6th, negative values refer to the past:
+/// - An integer ``k`` gives a New Moon
+/// - Adding ``0.25``, ``0.5`` or ``0.75`` gives the First Quarter, Full Moon or Last Quarter respectively
+/// 
+/// Any other value of ``k`` is rounded to the nearest quarter.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn moon_phase(k: Decimal) -> JulianDay {
+    let k = (k * dec!(4)).round() / dec!(4);
+    let t = k / dec!(1236.85);
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let jde = MEAN_NEW_MOON_EPOCH + SYNODIC_MONTH * k
+        + dec!(0.00015437) * t2
+        - dec!(0.000000150) * t3
+        + dec!(0.00000000073) * t4;
+
+    let PhaseArguments { e, m, mp, f, omega } = phase_arguments(k);
+
+    let phase = k - k.floor();
+    let terms = if phase == Decimal::ZERO {
+        &NEW_MOON_TERMS
+    } else if phase == dec!(0.5) {
+        &FULL_MOON_TERMS
+    } else {
+        &QUARTER_TERMS
+    };
+
+    let mut correction: Decimal = terms.iter()
+        .map(|&(c, e_pow, n_mp, n_m, n_f)| {
+            let arg = Decimal::from(n_mp) * mp + Decimal::from(n_m) * m + Decimal::from(n_f) * f;
+            c * e.powu(u64::from(e_pow)) * sin_deg(arg)
+        })
+        .sum();
+
+    correction -= dec!(0.00017) * sin_deg(omega);
+
+    if phase == dec!(0.25) || phase == dec!(0.75) {
+        let w = dec!(0.00306)
+            - dec!(0.00038) * e * cos_deg(m)
+            + dec!(0.00026) * cos_deg(mp)
+            - dec!(0.00002) * cos_deg(mp - m)
+            + dec!(0.00002) * cos_deg(mp + m)
+            + dec!(0.00002) * cos_deg(dec!(2) * f);
+
+        if phase == dec!(0.25) {
+            correction += w;
+        } else {
+            correction -= w;
+        }
+    }
+
+    let a1 = dec!(299.77) + dec!(0.107408) * k - dec!(0.009173) * t2;
+    let planetary: Decimal = PLANETARY_TERMS.iter()
+        .map(|&(c, a0, a_k)| c * sin_deg(a0 + a_k * k))
+        .sum();
+
+    JulianDay::new(jde + correction + dec!(0.000325) * sin_deg(a1) + planetary)
+}
+
+/// Returns the instant of the first New Moon strictly after ``after``.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn next_new_moon(after: JulianDay) -> JulianDay {
+    let mut k = mean_lunation(after) - Decimal::ONE;
+
+    loop {
+        let jde = moon_phase(k);
+        if jde.day > after.day {
+            return jde;
+        }
+        k += Decimal::ONE;
+    }
+}
+
+/// Returns the instant of every Full Moon falling within the given year, in chronological order.\
+/// A year has 12 or 13 Full Moons, the second one in a calendar month being commonly called a "blue moon".
+/// 
+/// **NOTE:** Unlike ``moon_phase``, the instants are converted to Universal Time (UT) and rounded to 5 decimal places of a day (under a second),
+/// so that they fall on the civil date they are usually quoted for.\
+/// Full Moons before JulianDay 0 are skipped, as they cannot be converted into a CalendarDate.
+pub fn full_moons_in_year(year: i32) -> Vec<CalendarDate> {
+    let start = JulianDay::from(CalendarDate::from_ymd(year, 1, 1));
+    let end = JulianDay::from(CalendarDate::from_ymd(year + 1, 1, 1));
+
+    let mut k = mean_lunation(start) - dec!(0.5);
+    let mut full_moons = Vec::new();
+
+    loop {
+        let ut = moon_phase(k).in_scale(TimeScale::TD).convert_scale(TimeScale::UT).jd.round_to(5);
+        if ut.day >= end.day {
+            return full_moons;
+        }
+        if ut.day >= start.day && let Ok(date) = CalendarDate::try_from(ut) {
+            full_moons.push(date);
+        }
+        k += Decimal::ONE;
+    }
+}
+
+/// Returns the geocentric ``(longitude, latitude, distance)`` of the Moon, referred to the mean equinox of the date.\
+/// The longitude lies between 0 and 360 and the latitude between -90 and 90, both in degrees.
+/// The distance between the centers of the Earth and the Moon is in kilometers.
+/// 
+/// Taken from "Position of the Moon", accurate to about 10" in longitude and 4" in latitude.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn moon_position(jd: JulianDay) -> (Decimal, Decimal, Decimal) {
+    let t = jd.centuries_since_j2000();
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    // Mean longitude, mean elongation, the Sun's and the Moon's mean anomalies, and the argument of latitude
+    let lp = dec!(218.3164477) + dec!(481267.88123421) * t - dec!(0.0015786) * t2 + t3 / dec!(538841) - t4 / dec!(65194000);
+    let d = dec!(297.8501921) + dec!(445267.1114034) * t - dec!(0.0018819) * t2 + t3 / dec!(545868) - t4 / dec!(113065000);
+    let m = dec!(357.5291092) + dec!(35999.0502909) * t - dec!(0.0001536) * t2 + t3 / dec!(24490000);
+    let mp = dec!(134.9633964) + dec!(477198.8675055) * t + dec!(0.0087414) * t2 + t3 / dec!(69699) - t4 / dec!(14712000);
+    let f = dec!(93.2720950) + dec!(483202.0175233) * t - dec!(0.0036539) * t2 - t3 / dec!(3526000) + t4 / dec!(863310000);
+
+    let a1 = dec!(119.75) + dec!(131.849) * t;
+    let a2 = dec!(53.09) + dec!(479264.290) * t;
+    let a3 = dec!(313.45) + dec!(481266.484) * t;
+
+    // Terms involving M are multiplied by E, or E² for 2M, to account for the decreasing eccentricity of the Earth's orbit
+    let e = Decimal::ONE - dec!(0.002516) * t - dec!(0.0000074) * t2;
+    let argument = |cd: i8, cm: i8, cmp: i8, cf: i8| {
+        let arg = Decimal::from(cd) * d + Decimal::from(cm) * m + Decimal::from(cmp) * mp + Decimal::from(cf) * f;
+        (arg, e.powu(u64::from(cm.unsigned_abs())))
+    };
+
+    let mut sigma_l = Decimal::ZERO;
+    let mut sigma_r = Decimal::ZERO;
+    for (cd, cm, cmp, cf, l, r) in LONGITUDE_DISTANCE_TERMS {
+        let (arg, e_m) = argument(cd, cm, cmp, cf);
+        sigma_l += l * e_m * sin_deg(arg);
+        sigma_r += r * e_m * cos_deg(arg);
+    }
+
+    let mut sigma_b = Decimal::ZERO;
+    for (cd, cm, cmp, cf, b) in LATITUDE_TERMS {
+        let (arg, e_m) = argument(cd, cm, cmp, cf);
+        sigma_b += b * e_m * sin_deg(arg);
+    }
+
+    // Additive terms due to Venus, Jupiter and the flattening of the Earth
+    sigma_l += dec!(3958) * sin_deg(a1) + dec!(1962) * sin_deg(lp - f) + dec!(318) * sin_deg(a2);
+    sigma_b += dec!(-2235) * sin_deg(lp)
+        + dec!(382) * sin_deg(a3)
+        + dec!(175) * sin_deg(a1 - f)
+        + dec!(175) * sin_deg(a1 + f)
+        + dec!(127) * sin_deg(lp - mp)
+        - dec!(115) * sin_deg(lp + mp);
+
+    let longitude = normalize_degrees(lp + sigma_l / dec!(1000000));
+    let latitude = sigma_b / dec!(1000000);
+    let distance = dec!(385000.56) + sigma_r / dec!(1000);
+
+    (longitude, latitude, distance)
+}
+
+/// Returns the apparent ``(right ascension, declination)`` of the Moon in degrees, corrected for nutation.\
+/// The right ascension lies between 0 and 360, the declination between -90 and 90.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn moon_apparent_equatorial(jd: JulianDay) -> (Decimal, Decimal) {
+    let (longitude, beta, _) = moon_position(jd);
+    let (delta_psi, _) = nutation(jd);
+
+    let lambda = longitude + delta_psi / dec!(3600);
+    let epsilon = true_obliquity(jd);
+
+    let ra = normalize_degrees(atan2_deg(
+        sin_deg(lambda) * cos_deg(epsilon) - sin_deg(beta) / cos_deg(beta) * sin_deg(epsilon),
+        cos_deg(lambda),
+    ));
+    let dec = asin_deg(sin_deg(beta) * cos_deg(epsilon) + cos_deg(beta) * sin_deg(epsilon) * sin_deg(lambda));
+
+    (ra, dec)
+}
+
+/// Returns the phase angle of the Moon in degrees between 0 and 180, i.e. the angle Sun-Moon-Earth.\
+/// It is 0 at Full Moon and 180 at New Moon.
+/// 
+/// Taken from "Illuminated Fraction of the Moon's Disk"
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn moon_phase_angle(jd: JulianDay) -> Decimal {
+    let (alpha, delta) = moon_apparent_equatorial(jd);
+    let (_, _, distance) = moon_position(jd);
+
+    let (alpha0, delta0) = sun_apparent_equatorial(jd);
+    let (_, _, r) = planet_heliocentric(Planet::Earth, jd);
+    let r = r * ASTRONOMICAL_UNIT;
+
+    // Geocentric elongation of the Moon from the Sun
+    let psi = acos_deg(sin_deg(delta0) * sin_deg(delta) + cos_deg(delta0) * cos_deg(delta) * cos_deg(alpha0 - alpha));
+
+    atan2_deg(r * sin_deg(psi), distance - r * cos_deg(psi))
+}
+
+/// Returns the illuminated fraction of the Moon's disk, between 0 (New Moon) and 1 (Full Moon).
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn moon_illumination(jd: JulianDay) -> Decimal {
+    (Decimal::ONE + cos_deg(moon_phase_angle(jd))) / dec!(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::julian::CalendarDate;
+    use crate::moon::*;
+
+    #[test]
+    fn test_new_moon() {
+        // Example 49.a, the New Moon of 1977 February at 3h37m42s TD
+        let jde = moon_phase(dec!(-283));
+        assert_eq!(jde.round_to(5), JulianDay::new(dec!(2443192.65118)));
+
+        let date = CalendarDate::try_from(jde).unwrap();
+        let (h, m, s) = date.time_of_day();
+        assert_eq!((date.month(), date.day().trunc(), h, m, s.round()), (2, dec!(18), 3, 37, dec!(42)));
+    }
+
+    #[test]
+    fn test_last_quarter() {
+        // Example 49.b, the first Last Quarter of 2044 at 23h48m17s TD
+        let jde = moon_phase(dec!(544.75));
+        assert_eq!(jde.round_to(5), JulianDay::new(dec!(2467636.49186)));
+    }
+
+    #[test]
+    fn test_next_new_moon() {
+        let before = JulianDay::new(dec!(2443180.0));
+        assert_eq!(next_new_moon(before), moon_phase(dec!(-283)));
+
+        let exactly = moon_phase(dec!(-283));
+        assert_eq!(next_new_moon(exactly), moon_phase(dec!(-282)));
+
+        let j2000 = JulianDay::new(dec!(2451545.0));
+        assert_eq!(next_new_moon(j2000), moon_phase(dec!(0)));
+    }
+
+    #[test]
+    fn test_full_moons_in_year() {
+        // 2023 had a blue moon on August 31st, at 1h35m UT
+        let full_moons = full_moons_in_year(2023);
+        assert_eq!(full_moons.len(), 13);
+        assert_eq!(full_moons[0].to_string(), "2023-01-06");
+        assert_eq!(full_moons[7].to_string(), "2023-08-01");
+        assert_eq!(full_moons[8].to_string(), "2023-08-31");
+        assert_eq!(full_moons[12].to_string(), "2023-12-27");
+        let (h, m, _) = full_moons[8].time_of_day();
+        assert_eq!((h, m), (1, 35));
+
+        let full_moons = full_moons_in_year(2024);
+        assert_eq!(full_moons.len(), 12);
+        assert_eq!(full_moons[0].to_string(), "2024-01-25");
+        assert_eq!(full_moons[11].to_string(), "2024-12-15");
+
+        // The first candidate of -4712 falls before JulianDay 0, and every Full Moon of -4713 does
+        let full_moons = full_moons_in_year(-4712);
+        assert!((12..=13).contains(&full_moons.len()), "{} full moons", full_moons.len());
+        assert!(full_moons.iter().all(|date| date.year() == -4712));
+        assert!(full_moons_in_year(-4713).is_empty());
+    }
+
+    #[test]
+    fn test_moon_position() {
+        // Example 47.a, 1992 April 12.0 TD
+        let jd = JulianDay::new(dec!(2448724.5));
+        let (lambda, beta, distance) = moon_position(jd);
+        assert!((lambda - dec!(133.162655)).abs() < dec!(0.000001));
+        assert!((beta - dec!(-3.229126)).abs() < dec!(0.000001));
+        assert!((distance - dec!(368409.7)).abs() < dec!(0.1));
+
+        let (ra, dec) = moon_apparent_equatorial(jd);
+        assert!((ra - dec!(134.688470)).abs() < dec!(0.00001));
+        assert!((dec - dec!(13.768368)).abs() < dec!(0.00001));
+    }
+
+    #[test]
+    fn test_moon_illumination() {
+        // Example 48.a, 1992 April 12.0 TD
+        let jd = JulianDay::new(dec!(2448724.5));
+        assert!((moon_phase_angle(jd) - dec!(69.0756)).abs() < dec!(0.001));
+        assert_eq!(moon_illumination(jd).round_dp(4), dec!(0.6786));
+
+        // Nearly dark at New Moon and nearly fully lit at Full Moon, short of the Moon's latitude
+        assert!(moon_illumination(moon_phase(dec!(-283))) < dec!(0.005));
+        assert!(moon_illumination(moon_phase(dec!(-282.5))) > dec!(0.995));
+    }
+}
@@ -0,0 +1,151 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{cos_deg, normalize_degrees, sin_deg};
+use crate::julian::JulianDay;
+
+/// A periodic term of the nutation series: the multiples of ``D``, ``M``, ``M'``, ``F`` and ``Ω``,
+/// followed by the sine coefficient of ``Δψ`` and the cosine coefficient of ``Δε``, each as ``(constant, T)`` in units of 0.0001".
+type NutationTerm = (i8, i8, i8, i8, i8, (Decimal, Decimal), (Decimal, Decimal));
+
+const NUTATION_TERMS: [NutationTerm; 63] = [
+    (0, 0, 0, 0, 1, (dec!(-171996), dec!(-174.2)), (dec!(92025), dec!(8.9))),
+    (-2, 0, 0, 2, 2, (dec!(-13187), dec!(-1.6)), (dec!(5736), dec!(-3.1))),
+    (0, 0, 0, 2, 2, (dec!(-2274), dec!(-0.2)), (dec!(977), dec!(-0.5))),
+    (0, 0, 0, 0, 2, (dec!(2062), dec!(0.2)), (dec!(-895), dec!(0.5))),
+    (0, 1, 0, 0, 0, (dec!(1426), dec!(-3.4)), (dec!(54), dec!(-0.1))),
+    (0, 0, 1, 0, 0, (dec!(712), dec!(0.1)), (dec!(-7), dec!(0))),
+    (-2, 1, 0, 2, 2, (dec!(-517), dec!(1.2)), (dec!(224), dec!(-0.6))),
+    (0, 0, 0, 2, 1, (dec!(-386), dec!(-0.4)), (dec!(200), dec!(0))),
+    (0, 0, 1, 2, 2, (dec!(-301), dec!(0)), (dec!(129), dec!(-0.1))),
+    (-2, -1, 0, 2, 2, (dec!(217), dec!(-0.5)), (dec!(-95), dec!(0.3))),
+    (-2, 0, 1, 0, 0, (dec!(-158), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 0, 0, 2, 1, (dec!(129), dec!(0.1)), (dec!(-70), dec!(0))),
+    (0, 0, -1, 2, 2, (dec!(123), dec!(0)), (dec!(-53), dec!(0))),
+    (2, 0, 0, 0, 0, (dec!(63), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, 1, 0, 1, (dec!(63), dec!(0.1)), (dec!(-33), dec!(0))),
+    (2, 0, -1, 2, 2, (dec!(-59), dec!(0)), (dec!(26), dec!(0))),
+    (0, 0, -1, 0, 1, (dec!(-58), dec!(-0.1)), (dec!(32), dec!(0))),
+    (0, 0, 1, 2, 1, (dec!(-51), dec!(0)), (dec!(27), dec!(0))),
+    (-2, 0, 2, 0, 0, (dec!(48), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, -2, 2, 1, (dec!(46), dec!(0)), (dec!(-24), dec!(0))),
+    (2, 0, 0, 2, 2, (dec!(-38), dec!(0)), (dec!(16), dec!(0))),
+    (0, 0, 2, 2, 2, (dec!(-31), dec!(0)), (dec!(13), dec!(0))),
+    (0, 0, 2, 0, 0, (dec!(29), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 0, 1, 2, 2, (dec!(29), dec!(0)), (dec!(-12), dec!(0))),
+    (0, 0, 0, 2, 0, (dec!(26), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 0, 0, 2, 0, (dec!(-22), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, -1, 2, 1, (dec!(21), dec!(0)), (dec!(-10), dec!(0))),
+    (0, 2, 0, 0, 0, (dec!(17), dec!(-0.1)), (dec!(0), dec!(0))),
+    (2, 0, -1, 0, 1, (dec!(16), dec!(0)), (dec!(-8), dec!(0))),
+    (-2, 2, 0, 2, 2, (dec!(-16), dec!(0.1)), (dec!(7), dec!(0))),
+    (0, 1, 0, 0, 1, (dec!(-15), dec!(0)), (dec!(9), dec!(0))),
+    (-2, 0, 1, 0, 1, (dec!(-13), dec!(0)), (dec!(7), dec!(0))),
+    (0, -1, 0, 0, 1, (dec!(-12), dec!(0)), (dec!(6), dec!(0))),
+    (0, 0, 2, -2, 0, (dec!(11), dec!(0)), (dec!(0), dec!(0))),
+    (2, 0, -1, 2, 1, (dec!(-10), dec!(0)), (dec!(5), dec!(0))),
+    (2, 0, 1, 2, 2, (dec!(-8), dec!(0)), (dec!(3), dec!(0))),
+    (0, 1, 0, 2, 2, (dec!(7), dec!(0)), (dec!(-3), dec!(0))),
+    (-2, 1, 1, 0, 0, (dec!(-7), dec!(0)), (dec!(0), dec!(0))),
+    (0, -1, 0, 2, 2, (dec!(-7), dec!(0)), (dec!(3), dec!(0))),
+    (2, 0, 0, 2, 1, (dec!(-7), dec!(0)), (dec!(3), dec!(0))),
+    (2, 0, 1, 0, 0, (dec!(6), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 0, 2, 2, 2, (dec!(6), dec!(0)), (dec!(-3), dec!(0))),
+    (-2, 0, 1, 2, 1, (dec!(6), dec!(0)), (dec!(-3), dec!(0))),
+    (2, 0, -2, 0, 1, (dec!(-6), dec!(0)), (dec!(3), dec!(0))),
+    (2, 0, 0, 0, 1, (dec!(-6), dec!(0)), (dec!(3), dec!(0))),
+    (0, -1, 1, 0, 0, (dec!(5), dec!(0)), (dec!(0), dec!(0))),
+    (-2, -1, 0, 2, 1, (dec!(-5), dec!(0)), (dec!(3), dec!(0))),
+    (-2, 0, 0, 0, 1, (dec!(-5), dec!(0)), (dec!(3), dec!(0))),
+    (0, 0, 2, 2, 1, (dec!(-5), dec!(0)), (dec!(3), dec!(0))),
+    (-2, 0, 2, 0, 1, (dec!(4), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 1, 0, 2, 1, (dec!(4), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, 1, -2, 0, (dec!(4), dec!(0)), (dec!(0), dec!(0))),
+    (-1, 0, 1, 0, 0, (dec!(-4), dec!(0)), (dec!(0), dec!(0))),
+    (-2, 1, 0, 0, 0, (dec!(-4), dec!(0)), (dec!(0), dec!(0))),
+    (1, 0, 0, 0, 0, (dec!(-4), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, 1, 2, 0, (dec!(3), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, -2, 2, 2, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (-1, -1, 1, 0, 0, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (0, 1, 1, 0, 0, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (0, -1, 1, 2, 2, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (2, -1, -1, 2, 2, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (0, 0, 3, 2, 2, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+    (2, -1, 0, 2, 2, (dec!(-3), dec!(0)), (dec!(0), dec!(0))),
+];
+
+/// Returns the mean obliquity of the ecliptic in degrees.
+/// 
+/// Taken from "Nutation and the Obliquity of the Ecliptic", using the IAU formula which is accurate to about 1" over 2000 years.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn mean_obliquity(jd: JulianDay) -> Decimal {
+    let t = jd.centuries_since_j2000();
+
+    // 23°26'21.448" - 46.8150" T - 0.00059" T² + 0.001813" T³
+    let seconds = dec!(21.448) - dec!(46.8150) * t - dec!(0.00059) * t * t + dec!(0.001813) * t * t * t;
+    dec!(23) + dec!(26) / dec!(60) + seconds / dec!(3600)
+}
+
+/// Returns the true obliquity of the ecliptic in degrees, i.e. the mean obliquity corrected for the nutation in obliquity.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn true_obliquity(jd: JulianDay) -> Decimal {
+    let (_, delta_epsilon) = nutation(jd);
+    mean_obliquity(jd) + delta_epsilon / dec!(3600)
+}
+
+/// Returns the nutation ``(in longitude, in obliquity)``, i.e. ``(Δψ, Δε)``, in arcseconds.
+/// 
+/// Taken from "Nutation and the Obliquity of the Ecliptic", using the full IAU 1980 series.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn nutation(jd: JulianDay) -> (Decimal, Decimal) {
+    let t = jd.centuries_since_j2000();
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // Mean elongation of the Moon from the Sun
+    let d = normalize_degrees(dec!(297.85036) + dec!(445267.111480) * t - dec!(0.0019142) * t2 + t3 / dec!(189474));
+    // Mean anomaly of the Sun
+    let m = normalize_degrees(dec!(357.52772) + dec!(35999.050340) * t - dec!(0.0001603) * t2 - t3 / dec!(300000));
+    // Mean anomaly of the Moon
+    let m1 = normalize_degrees(dec!(134.96298) + dec!(477198.867398) * t + dec!(0.0086972) * t2 + t3 / dec!(56250));
+    // Moon's argument of latitude
+    let f = normalize_degrees(dec!(93.27191) + dec!(483202.017538) * t - dec!(0.0036825) * t2 + t3 / dec!(327270));
+    // Longitude of the ascending node of the Moon's mean orbit
+    let omega = normalize_degrees(dec!(125.04452) - dec!(1934.136261) * t + dec!(0.0020708) * t2 + t3 / dec!(450000));
+
+    let mut delta_psi = Decimal::ZERO;
+    let mut delta_epsilon = Decimal::ZERO;
+    for (cd, cm, cm1, cf, co, (psi, psi_t), (eps, eps_t)) in NUTATION_TERMS {
+        let argument = Decimal::from(cd) * d + Decimal::from(cm) * m + Decimal::from(cm1) * m1 + Decimal::from(cf) * f + Decimal::from(co) * omega;
+
+        delta_psi += (psi + psi_t * t) * sin_deg(argument);
+        delta_epsilon += (eps + eps_t * t) * cos_deg(argument);
+    }
+
+    (delta_psi / dec!(10000), delta_epsilon / dec!(10000))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nutation::*;
+
+    #[test]
+    fn test_nutation() {
+        // Example 22.a, 1987 April 10.0 TD
+        let jd = JulianDay::new(dec!(2446895.5));
+        let (delta_psi, delta_epsilon) = nutation(jd);
+        assert_eq!(delta_psi.round_dp(3), dec!(-3.788));
+        assert_eq!(delta_epsilon.round_dp(3), dec!(9.443));
+    }
+
+    #[test]
+    fn test_obliquity() {
+        // Example 22.a, ε0 = 23°26'27.407" and ε = 23°26'36.850"
+        let jd = JulianDay::new(dec!(2446895.5));
+        assert!((mean_obliquity(jd) - dec!(23.4409464)).abs() < dec!(0.0000003));
+        assert!((true_obliquity(jd) - dec!(23.4435694)).abs() < dec!(0.0000003));
+    }
+}
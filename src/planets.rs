@@ -0,0 +1,1051 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use rust_decimal::prelude::*;
+
+use crate::angle::{acos_deg, atan2_deg, cos_deg, normalize_degrees, sin_deg, to_degrees};
+use crate::julian::JulianDay;
+
+/// A periodic term ``A cos(B + C τ)`` of a VSOP87 series, with ``A`` in units of 10⁻⁸ radian or AU.
+type VsopTerm = (Decimal, Decimal, Decimal);
+
+const MERCURY_L: [&[VsopTerm]; 6] = [
+    &[
+        (dec!(440250710), dec!(0), dec!(0)),
+        (dec!(40989415), dec!(1.48302034), dec!(26087.90314157)),
+        (dec!(5046294), dec!(4.4778549), dec!(52175.8062831)),
+        (dec!(855347), dec!(1.165203), dec!(78263.709425)),
+        (dec!(165590), dec!(4.119692), dec!(104351.612566)),
+        (dec!(34562), dec!(0.77931), dec!(130439.51571)),
+        (dec!(7583), dec!(3.7135), dec!(156527.4188)),
+        (dec!(3560), dec!(1.5120), dec!(1109.3786)),
+        (dec!(1803), dec!(4.1033), dec!(5661.3320)),
+        (dec!(1726), dec!(0.3583), dec!(182615.3220)),
+        (dec!(1590), dec!(2.9951), dec!(25028.5212)),
+        (dec!(1365), dec!(4.5992), dec!(27197.2817)),
+        (dec!(1017), dec!(0.8803), dec!(31749.2352)),
+        (dec!(714), dec!(1.541), dec!(24978.525)),
+        (dec!(644), dec!(5.303), dec!(21535.950)),
+        (dec!(451), dec!(6.050), dec!(51116.424)),
+        (dec!(404), dec!(3.282), dec!(208703.225)),
+        (dec!(352), dec!(5.242), dec!(20426.571)),
+        (dec!(345), dec!(2.792), dec!(15874.618)),
+        (dec!(343), dec!(5.765), dec!(955.600)),
+        (dec!(339), dec!(5.863), dec!(25558.212)),
+        (dec!(325), dec!(1.337), dec!(53285.185)),
+        (dec!(273), dec!(2.495), dec!(529.691)),
+        (dec!(264), dec!(3.917), dec!(57837.138)),
+        (dec!(260), dec!(0.987), dec!(4551.953)),
+        (dec!(239), dec!(0.113), dec!(1059.382)),
+        (dec!(235), dec!(0.267), dec!(11322.664)),
+        (dec!(217), dec!(0.660), dec!(13521.751)),
+        (dec!(209), dec!(2.092), dec!(47623.853)),
+        (dec!(183), dec!(2.629), dec!(27043.503)),
+        (dec!(182), dec!(2.434), dec!(25661.305)),
+        (dec!(176), dec!(4.536), dec!(51066.428)),
+        (dec!(173), dec!(2.452), dec!(24498.830)),
+        (dec!(142), dec!(3.360), dec!(37410.567)),
+        (dec!(138), dec!(0.291), dec!(10213.286)),
+        (dec!(125), dec!(3.721), dec!(39609.655)),
+        (dec!(118), dec!(2.781), dec!(77204.327)),
+        (dec!(106), dec!(4.206), dec!(19804.827)),
+    ],
+    &[
+        (dec!(2608814706223), dec!(0), dec!(0)),
+        (dec!(1126008), dec!(6.2170397), dec!(26087.9031416)),
+        (dec!(303471), dec!(3.055655), dec!(52175.806283)),
+        (dec!(80538), dec!(6.10455), dec!(78263.70942)),
+        (dec!(21245), dec!(2.83532), dec!(104351.61257)),
+        (dec!(5592), dec!(5.8268), dec!(130439.5157)),
+        (dec!(1472), dec!(2.5185), dec!(156527.4188)),
+        (dec!(388), dec!(5.480), dec!(182615.322)),
+        (dec!(352), dec!(3.052), dec!(1109.379)),
+        (dec!(103), dec!(2.149), dec!(208703.225)),
+        (dec!(94), dec!(6.12), dec!(27197.28)),
+        (dec!(91), dec!(0.00), dec!(24978.52)),
+        (dec!(52), dec!(5.62), dec!(5661.33)),
+        (dec!(44), dec!(4.57), dec!(25028.52)),
+        (dec!(28), dec!(3.04), dec!(51066.43)),
+        (dec!(27), dec!(5.09), dec!(234791.13)),
+    ],
+    &[
+        (dec!(53050), dec!(0), dec!(0)),
+        (dec!(16904), dec!(4.69072), dec!(26087.90314)),
+        (dec!(7397), dec!(1.3474), dec!(52175.8063)),
+        (dec!(3018), dec!(4.4564), dec!(78263.7094)),
+        (dec!(1107), dec!(1.2623), dec!(104351.6126)),
+        (dec!(378), dec!(4.320), dec!(130439.516)),
+        (dec!(123), dec!(1.069), dec!(156527.419)),
+        (dec!(39), dec!(4.08), dec!(182615.32)),
+        (dec!(15), dec!(4.63), dec!(1109.38)),
+        (dec!(12), dec!(0.79), dec!(208703.23)),
+    ],
+    &[
+        (dec!(188), dec!(0.035), dec!(52175.806)),
+        (dec!(142), dec!(3.125), dec!(26087.903)),
+        (dec!(97), dec!(3.00), dec!(78263.71)),
+        (dec!(44), dec!(6.02), dec!(104351.61)),
+        (dec!(35), dec!(0), dec!(0)),
+        (dec!(18), dec!(2.78), dec!(130439.52)),
+        (dec!(7), dec!(5.82), dec!(156527.42)),
+        (dec!(3), dec!(2.57), dec!(182615.32)),
+    ],
+    &[
+        (dec!(114), dec!(3.1416), dec!(0)),
+        (dec!(2), dec!(2.03), dec!(26087.90)),
+        (dec!(2), dec!(1.42), dec!(78263.71)),
+        (dec!(2), dec!(4.50), dec!(52175.81)),
+        (dec!(1), dec!(4.50), dec!(104351.61)),
+        (dec!(1), dec!(1.27), dec!(130439.52)),
+    ],
+    &[
+        (dec!(1), dec!(3.14), dec!(0)),
+    ],
+];
+
+const MERCURY_B: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(11737529), dec!(1.98357499), dec!(26087.90314157)),
+        (dec!(2388077), dec!(5.0373896), dec!(52175.8062831)),
+        (dec!(1222840), dec!(3.1415927), dec!(0)),
+        (dec!(543252), dec!(1.796444), dec!(78263.709425)),
+        (dec!(129779), dec!(4.832325), dec!(104351.612566)),
+        (dec!(31867), dec!(1.58088), dec!(130439.51571)),
+        (dec!(7963), dec!(4.6097), dec!(156527.4188)),
+        (dec!(2014), dec!(1.3532), dec!(182615.3220)),
+        (dec!(514), dec!(4.378), dec!(208703.225)),
+        (dec!(209), dec!(2.020), dec!(24978.525)),
+        (dec!(208), dec!(4.918), dec!(27197.282)),
+        (dec!(132), dec!(1.119), dec!(234791.128)),
+        (dec!(121), dec!(1.813), dec!(53285.185)),
+        (dec!(100), dec!(5.657), dec!(20426.571)),
+    ],
+    &[
+        (dec!(429151), dec!(3.501698), dec!(26087.903142)),
+        (dec!(146234), dec!(3.141593), dec!(0)),
+        (dec!(22675), dec!(0.01515), dec!(52175.80628)),
+        (dec!(10895), dec!(0.48540), dec!(78263.70942)),
+        (dec!(6353), dec!(3.4294), dec!(104351.6126)),
+        (dec!(2496), dec!(0.1605), dec!(130439.5157)),
+        (dec!(860), dec!(3.185), dec!(156527.419)),
+        (dec!(278), dec!(6.210), dec!(182615.322)),
+        (dec!(86), dec!(2.95), dec!(208703.23)),
+        (dec!(28), dec!(0.29), dec!(27197.28)),
+        (dec!(26), dec!(5.98), dec!(234791.13)),
+    ],
+    &[
+        (dec!(11831), dec!(4.79066), dec!(26087.90314)),
+        (dec!(1914), dec!(0), dec!(0)),
+        (dec!(1045), dec!(1.2122), dec!(52175.8063)),
+        (dec!(266), dec!(4.434), dec!(78263.709)),
+        (dec!(170), dec!(1.623), dec!(104351.613)),
+        (dec!(96), dec!(4.80), dec!(130439.52)),
+        (dec!(45), dec!(1.61), dec!(156527.42)),
+        (dec!(18), dec!(4.67), dec!(182615.32)),
+        (dec!(7), dec!(1.43), dec!(208703.23)),
+    ],
+    &[
+        (dec!(235), dec!(0.354), dec!(26087.903)),
+        (dec!(161), dec!(0), dec!(0)),
+        (dec!(19), dec!(4.36), dec!(52175.81)),
+        (dec!(6), dec!(2.51), dec!(78263.71)),
+        (dec!(5), dec!(6.14), dec!(104351.61)),
+        (dec!(3), dec!(3.12), dec!(130439.52)),
+        (dec!(2), dec!(6.27), dec!(156527.42)),
+    ],
+    &[
+        (dec!(4), dec!(1.75), dec!(26087.90)),
+        (dec!(1), dec!(3.14), dec!(0)),
+    ],
+];
+
+const MERCURY_R: [&[VsopTerm]; 4] = [
+    &[
+        (dec!(39528272), dec!(0), dec!(0)),
+        (dec!(7834132), dec!(6.1923372), dec!(26087.9031416)),
+        (dec!(795526), dec!(2.959897), dec!(52175.806283)),
+        (dec!(121282), dec!(6.010642), dec!(78263.709425)),
+        (dec!(21922), dec!(2.77820), dec!(104351.61257)),
+        (dec!(4354), dec!(5.8289), dec!(130439.5157)),
+        (dec!(918), dec!(2.597), dec!(156527.419)),
+        (dec!(290), dec!(1.424), dec!(25028.521)),
+        (dec!(260), dec!(3.028), dec!(27197.282)),
+        (dec!(202), dec!(5.647), dec!(182615.322)),
+        (dec!(201), dec!(5.592), dec!(31749.235)),
+        (dec!(142), dec!(6.253), dec!(24978.525)),
+        (dec!(100), dec!(3.734), dec!(21535.950)),
+    ],
+    &[
+        (dec!(217348), dec!(4.656172), dec!(26087.903142)),
+        (dec!(44142), dec!(1.42386), dec!(52175.80628)),
+        (dec!(10094), dec!(4.47466), dec!(78263.70942)),
+        (dec!(2433), dec!(1.2423), dec!(104351.6126)),
+        (dec!(1624), dec!(0), dec!(0)),
+        (dec!(604), dec!(4.293), dec!(130439.516)),
+        (dec!(153), dec!(1.061), dec!(156527.419)),
+        (dec!(39), dec!(4.11), dec!(182615.32)),
+    ],
+    &[
+        (dec!(3118), dec!(3.0823), dec!(26087.9031)),
+        (dec!(1245), dec!(6.1518), dec!(52175.8063)),
+        (dec!(425), dec!(2.926), dec!(78263.709)),
+        (dec!(136), dec!(5.980), dec!(104351.613)),
+        (dec!(42), dec!(2.75), dec!(130439.52)),
+        (dec!(22), dec!(3.14), dec!(0)),
+        (dec!(13), dec!(5.80), dec!(156527.42)),
+    ],
+    &[
+        (dec!(33), dec!(1.68), dec!(26087.90)),
+        (dec!(24), dec!(4.63), dec!(52175.81)),
+        (dec!(12), dec!(1.39), dec!(78263.71)),
+        (dec!(5), dec!(4.44), dec!(104351.61)),
+        (dec!(2), dec!(1.21), dec!(130439.52)),
+    ],
+];
+
+const VENUS_L: [&[VsopTerm]; 6] = [
+    &[
+        (dec!(317614667), dec!(0), dec!(0)),
+        (dec!(1353968), dec!(5.5931332), dec!(10213.2855462)),
+        (dec!(89892), dec!(5.30650), dec!(20426.57109)),
+        (dec!(5477), dec!(4.4163), dec!(7860.4194)),
+        (dec!(3456), dec!(2.6996), dec!(11790.6291)),
+        (dec!(2372), dec!(2.9938), dec!(3930.2097)),
+        (dec!(1664), dec!(4.2502), dec!(1577.3435)),
+        (dec!(1438), dec!(4.1575), dec!(9683.5946)),
+        (dec!(1317), dec!(5.1867), dec!(26.2983)),
+        (dec!(1201), dec!(6.1536), dec!(30639.8566)),
+        (dec!(769), dec!(0.816), dec!(9437.763)),
+        (dec!(761), dec!(1.950), dec!(529.691)),
+        (dec!(708), dec!(1.065), dec!(775.523)),
+        (dec!(585), dec!(3.998), dec!(191.448)),
+        (dec!(500), dec!(4.123), dec!(15720.839)),
+        (dec!(429), dec!(3.586), dec!(19367.189)),
+        (dec!(327), dec!(5.677), dec!(5507.553)),
+        (dec!(326), dec!(4.591), dec!(10404.734)),
+        (dec!(232), dec!(3.163), dec!(9153.904)),
+        (dec!(180), dec!(4.653), dec!(1109.379)),
+        (dec!(155), dec!(5.570), dec!(19651.048)),
+        (dec!(128), dec!(4.226), dec!(20.775)),
+        (dec!(128), dec!(0.962), dec!(5661.332)),
+        (dec!(106), dec!(1.537), dec!(801.821)),
+    ],
+    &[
+        (dec!(1021352943053), dec!(0), dec!(0)),
+        (dec!(95708), dec!(2.46424), dec!(10213.28555)),
+        (dec!(14445), dec!(0.51625), dec!(20426.57109)),
+        (dec!(213), dec!(1.795), dec!(30639.857)),
+        (dec!(174), dec!(2.655), dec!(26.298)),
+        (dec!(152), dec!(6.106), dec!(1577.344)),
+        (dec!(82), dec!(5.70), dec!(191.45)),
+        (dec!(70), dec!(2.68), dec!(9437.76)),
+        (dec!(52), dec!(3.60), dec!(775.52)),
+        (dec!(38), dec!(1.03), dec!(529.69)),
+        (dec!(30), dec!(1.25), dec!(5507.55)),
+        (dec!(25), dec!(6.11), dec!(10404.73)),
+    ],
+    &[
+        (dec!(54127), dec!(0), dec!(0)),
+        (dec!(3891), dec!(0.3451), dec!(10213.2855)),
+        (dec!(1338), dec!(2.0201), dec!(20426.5711)),
+        (dec!(24), dec!(2.05), dec!(26.30)),
+        (dec!(19), dec!(3.54), dec!(30639.86)),
+        (dec!(10), dec!(3.97), dec!(775.52)),
+        (dec!(7), dec!(1.52), dec!(1577.34)),
+        (dec!(6), dec!(1.00), dec!(191.45)),
+    ],
+    &[
+        (dec!(136), dec!(4.804), dec!(10213.286)),
+        (dec!(78), dec!(3.67), dec!(20426.57)),
+        (dec!(26), dec!(0), dec!(0)),
+    ],
+    &[
+        (dec!(114), dec!(3.1416), dec!(0)),
+        (dec!(3), dec!(5.21), dec!(20426.57)),
+        (dec!(2), dec!(2.51), dec!(10213.29)),
+    ],
+    &[
+        (dec!(1), dec!(3.14), dec!(0)),
+    ],
+];
+
+const VENUS_B: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(5923638), dec!(0.2670278), dec!(10213.2855462)),
+        (dec!(40108), dec!(1.14737), dec!(20426.57109)),
+        (dec!(32815), dec!(3.14159), dec!(0)),
+        (dec!(1011), dec!(1.0895), dec!(30639.8566)),
+        (dec!(149), dec!(6.254), dec!(18073.705)),
+        (dec!(138), dec!(0.860), dec!(1577.344)),
+        (dec!(130), dec!(3.672), dec!(9437.763)),
+        (dec!(120), dec!(3.705), dec!(2352.866)),
+        (dec!(108), dec!(4.539), dec!(22003.915)),
+    ],
+    &[
+        (dec!(513348), dec!(1.803643), dec!(10213.285546)),
+        (dec!(4380), dec!(3.3862), dec!(20426.5711)),
+        (dec!(199), dec!(0), dec!(0)),
+        (dec!(197), dec!(2.530), dec!(30639.857)),
+    ],
+    &[
+        (dec!(22378), dec!(3.38509), dec!(10213.28555)),
+        (dec!(282), dec!(0), dec!(0)),
+        (dec!(173), dec!(5.256), dec!(20426.571)),
+        (dec!(27), dec!(3.87), dec!(30639.86)),
+    ],
+    &[
+        (dec!(647), dec!(4.992), dec!(10213.286)),
+        (dec!(20), dec!(3.14), dec!(0)),
+        (dec!(6), dec!(0.77), dec!(20426.57)),
+        (dec!(3), dec!(5.44), dec!(30639.86)),
+    ],
+    &[
+        (dec!(14), dec!(0.32), dec!(10213.29)),
+    ],
+];
+
+const VENUS_R: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(72334821), dec!(0), dec!(0)),
+        (dec!(489824), dec!(4.021518), dec!(10213.285546)),
+        (dec!(1658), dec!(4.9021), dec!(20426.5711)),
+        (dec!(1632), dec!(2.8455), dec!(7860.4194)),
+        (dec!(1378), dec!(1.1285), dec!(11790.6291)),
+        (dec!(498), dec!(2.587), dec!(9683.595)),
+        (dec!(374), dec!(1.423), dec!(3930.210)),
+        (dec!(264), dec!(5.529), dec!(9437.763)),
+        (dec!(237), dec!(2.551), dec!(15720.839)),
+        (dec!(222), dec!(2.013), dec!(19367.189)),
+        (dec!(126), dec!(2.728), dec!(1577.344)),
+        (dec!(119), dec!(3.020), dec!(10404.734)),
+    ],
+    &[
+        (dec!(34551), dec!(0.89199), dec!(10213.28555)),
+        (dec!(234), dec!(1.772), dec!(20426.571)),
+        (dec!(234), dec!(3.142), dec!(0)),
+    ],
+    &[
+        (dec!(1407), dec!(5.0637), dec!(10213.2855)),
+        (dec!(16), dec!(5.47), dec!(20426.57)),
+        (dec!(13), dec!(0), dec!(0)),
+    ],
+    &[
+        (dec!(50), dec!(3.22), dec!(10213.29)),
+    ],
+    &[
+        (dec!(1), dec!(0.92), dec!(10213.29)),
+    ],
+];
+
+const EARTH_L: [&[VsopTerm]; 6] = [
+    &[
+        (dec!(175347046), dec!(0), dec!(0)),
+        (dec!(3341656), dec!(4.6692568), dec!(6283.0758500)),
+        (dec!(34894), dec!(4.62610), dec!(12566.15170)),
+        (dec!(3497), dec!(2.7441), dec!(5753.3849)),
+        (dec!(3418), dec!(2.8289), dec!(3.5231)),
+        (dec!(3136), dec!(3.6277), dec!(77713.7715)),
+        (dec!(2676), dec!(4.4181), dec!(7860.4194)),
+        (dec!(2343), dec!(6.1352), dec!(3930.2097)),
+        (dec!(1324), dec!(0.7425), dec!(11506.7698)),
+        (dec!(1273), dec!(2.0371), dec!(529.6910)),
+        (dec!(1199), dec!(1.1096), dec!(1577.3435)),
+        (dec!(990), dec!(5.233), dec!(5884.927)),
+        (dec!(902), dec!(2.045), dec!(26.298)),
+        (dec!(857), dec!(3.508), dec!(398.149)),
+        (dec!(780), dec!(1.179), dec!(5223.694)),
+        (dec!(753), dec!(2.533), dec!(5507.553)),
+        (dec!(505), dec!(4.583), dec!(18849.228)),
+        (dec!(492), dec!(4.205), dec!(775.523)),
+        (dec!(357), dec!(2.920), dec!(0.067)),
+        (dec!(317), dec!(5.849), dec!(11790.629)),
+        (dec!(284), dec!(1.899), dec!(796.298)),
+        (dec!(271), dec!(0.315), dec!(10977.079)),
+        (dec!(243), dec!(0.345), dec!(5486.778)),
+        (dec!(206), dec!(4.806), dec!(2544.314)),
+        (dec!(205), dec!(1.869), dec!(5573.143)),
+        (dec!(202), dec!(2.458), dec!(6069.777)),
+        (dec!(156), dec!(0.833), dec!(213.299)),
+        (dec!(132), dec!(3.411), dec!(2942.463)),
+        (dec!(126), dec!(1.083), dec!(20.775)),
+        (dec!(115), dec!(0.645), dec!(0.980)),
+        (dec!(103), dec!(0.636), dec!(4694.003)),
+        (dec!(102), dec!(0.976), dec!(15720.839)),
+        (dec!(102), dec!(4.267), dec!(7.114)),
+        (dec!(99), dec!(6.21), dec!(2146.17)),
+        (dec!(98), dec!(0.68), dec!(155.42)),
+        (dec!(86), dec!(5.98), dec!(161000.69)),
+        (dec!(85), dec!(1.30), dec!(6275.96)),
+        (dec!(85), dec!(3.67), dec!(71430.70)),
+        (dec!(80), dec!(1.81), dec!(17260.15)),
+        (dec!(79), dec!(3.04), dec!(12036.46)),
+        (dec!(75), dec!(1.76), dec!(5088.63)),
+        (dec!(74), dec!(3.50), dec!(3154.69)),
+        (dec!(74), dec!(4.68), dec!(801.82)),
+        (dec!(70), dec!(0.83), dec!(9437.76)),
+        (dec!(62), dec!(3.98), dec!(8827.39)),
+        (dec!(61), dec!(1.82), dec!(7084.90)),
+        (dec!(57), dec!(2.78), dec!(6286.60)),
+        (dec!(56), dec!(4.39), dec!(14143.50)),
+        (dec!(56), dec!(3.47), dec!(6279.55)),
+        (dec!(52), dec!(0.19), dec!(12139.55)),
+        (dec!(52), dec!(1.33), dec!(1748.02)),
+        (dec!(51), dec!(0.28), dec!(5856.48)),
+        (dec!(49), dec!(0.49), dec!(1194.45)),
+        (dec!(41), dec!(5.37), dec!(8429.24)),
+        (dec!(41), dec!(2.40), dec!(19651.05)),
+        (dec!(39), dec!(6.17), dec!(10447.39)),
+        (dec!(37), dec!(6.04), dec!(10213.29)),
+        (dec!(37), dec!(2.57), dec!(1059.38)),
+        (dec!(36), dec!(1.71), dec!(2352.87)),
+        (dec!(36), dec!(1.78), dec!(6812.77)),
+        (dec!(33), dec!(0.59), dec!(17789.85)),
+        (dec!(30), dec!(0.44), dec!(83996.85)),
+        (dec!(30), dec!(2.74), dec!(1349.87)),
+        (dec!(25), dec!(3.16), dec!(4690.48)),
+    ],
+    &[
+        (dec!(628331966747), dec!(0), dec!(0)),
+        (dec!(206059), dec!(2.678235), dec!(6283.075850)),
+        (dec!(4303), dec!(2.6351), dec!(12566.1517)),
+        (dec!(425), dec!(1.590), dec!(3.523)),
+        (dec!(119), dec!(5.796), dec!(26.298)),
+        (dec!(109), dec!(2.966), dec!(1577.344)),
+        (dec!(93), dec!(2.59), dec!(18849.23)),
+        (dec!(72), dec!(1.14), dec!(529.69)),
+        (dec!(68), dec!(1.87), dec!(398.15)),
+        (dec!(67), dec!(4.41), dec!(5507.55)),
+        (dec!(59), dec!(2.89), dec!(5223.69)),
+        (dec!(56), dec!(2.17), dec!(155.42)),
+        (dec!(45), dec!(0.40), dec!(796.30)),
+        (dec!(36), dec!(0.47), dec!(775.52)),
+        (dec!(29), dec!(2.65), dec!(7.11)),
+        (dec!(21), dec!(5.34), dec!(0.98)),
+        (dec!(19), dec!(1.85), dec!(5486.78)),
+        (dec!(19), dec!(4.97), dec!(213.30)),
+        (dec!(17), dec!(2.99), dec!(6275.96)),
+        (dec!(16), dec!(0.03), dec!(2544.31)),
+        (dec!(16), dec!(1.43), dec!(2146.17)),
+        (dec!(15), dec!(1.21), dec!(10977.08)),
+        (dec!(12), dec!(2.83), dec!(1748.02)),
+        (dec!(12), dec!(3.26), dec!(5088.63)),
+        (dec!(12), dec!(5.27), dec!(1194.45)),
+        (dec!(12), dec!(2.08), dec!(4694.00)),
+        (dec!(11), dec!(0.77), dec!(553.57)),
+        (dec!(10), dec!(1.30), dec!(6286.60)),
+        (dec!(10), dec!(4.24), dec!(1349.87)),
+        (dec!(9), dec!(2.70), dec!(242.73)),
+        (dec!(9), dec!(5.64), dec!(951.72)),
+        (dec!(8), dec!(5.30), dec!(2352.87)),
+        (dec!(6), dec!(2.65), dec!(9437.76)),
+        (dec!(6), dec!(4.67), dec!(4690.48)),
+    ],
+    &[
+        (dec!(52919), dec!(0), dec!(0)),
+        (dec!(8720), dec!(1.0721), dec!(6283.0758)),
+        (dec!(309), dec!(0.867), dec!(12566.152)),
+        (dec!(27), dec!(0.05), dec!(3.52)),
+        (dec!(16), dec!(5.19), dec!(26.30)),
+        (dec!(16), dec!(3.68), dec!(155.42)),
+        (dec!(10), dec!(0.76), dec!(18849.23)),
+        (dec!(9), dec!(2.06), dec!(77713.77)),
+        (dec!(7), dec!(0.83), dec!(775.52)),
+        (dec!(5), dec!(4.66), dec!(1577.34)),
+        (dec!(4), dec!(1.03), dec!(7.11)),
+        (dec!(4), dec!(3.44), dec!(5573.14)),
+        (dec!(3), dec!(5.14), dec!(796.30)),
+        (dec!(3), dec!(6.05), dec!(5507.55)),
+        (dec!(3), dec!(1.19), dec!(242.73)),
+        (dec!(3), dec!(6.12), dec!(529.69)),
+        (dec!(3), dec!(0.31), dec!(398.15)),
+        (dec!(3), dec!(2.28), dec!(553.57)),
+        (dec!(2), dec!(4.38), dec!(5223.69)),
+        (dec!(2), dec!(3.75), dec!(0.98)),
+    ],
+    &[
+        (dec!(289), dec!(5.844), dec!(6283.076)),
+        (dec!(35), dec!(0), dec!(0)),
+        (dec!(17), dec!(5.49), dec!(12566.15)),
+        (dec!(3), dec!(5.20), dec!(155.42)),
+        (dec!(1), dec!(4.72), dec!(3.52)),
+        (dec!(1), dec!(5.30), dec!(18849.23)),
+        (dec!(1), dec!(5.97), dec!(242.73)),
+    ],
+    &[
+        (dec!(114), dec!(3.142), dec!(0)),
+        (dec!(8), dec!(4.13), dec!(6283.08)),
+        (dec!(1), dec!(3.84), dec!(12566.15)),
+    ],
+    &[
+        (dec!(1), dec!(3.14), dec!(0)),
+    ],
+];
+
+const EARTH_B: [&[VsopTerm]; 2] = [
+    &[
+        (dec!(280), dec!(3.199), dec!(84334.662)),
+        (dec!(102), dec!(5.422), dec!(5507.553)),
+        (dec!(80), dec!(3.88), dec!(5223.69)),
+        (dec!(44), dec!(3.70), dec!(2352.87)),
+        (dec!(32), dec!(4.00), dec!(1577.34)),
+    ],
+    &[
+        (dec!(9), dec!(3.90), dec!(5507.55)),
+        (dec!(6), dec!(1.73), dec!(5223.69)),
+    ],
+];
+
+const EARTH_R: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(100013989), dec!(0), dec!(0)),
+        (dec!(1670700), dec!(3.0984635), dec!(6283.0758500)),
+        (dec!(13956), dec!(3.05525), dec!(12566.15170)),
+        (dec!(3084), dec!(5.1985), dec!(77713.7715)),
+        (dec!(1628), dec!(1.1739), dec!(5753.3849)),
+        (dec!(1576), dec!(2.8469), dec!(7860.4194)),
+        (dec!(925), dec!(5.453), dec!(11506.770)),
+        (dec!(542), dec!(4.564), dec!(3930.210)),
+        (dec!(472), dec!(3.661), dec!(5884.927)),
+        (dec!(346), dec!(0.964), dec!(5507.553)),
+        (dec!(329), dec!(5.900), dec!(5223.694)),
+        (dec!(307), dec!(0.299), dec!(5573.143)),
+        (dec!(243), dec!(4.273), dec!(11790.629)),
+        (dec!(212), dec!(5.847), dec!(1577.344)),
+        (dec!(186), dec!(5.022), dec!(10977.079)),
+        (dec!(175), dec!(3.012), dec!(18849.228)),
+        (dec!(110), dec!(5.055), dec!(5486.778)),
+        (dec!(98), dec!(0.89), dec!(6069.78)),
+        (dec!(86), dec!(5.69), dec!(15720.84)),
+        (dec!(86), dec!(1.27), dec!(161000.69)),
+        (dec!(65), dec!(0.27), dec!(17260.15)),
+        (dec!(63), dec!(0.92), dec!(529.69)),
+        (dec!(57), dec!(2.01), dec!(83996.85)),
+        (dec!(56), dec!(5.24), dec!(71430.70)),
+        (dec!(49), dec!(3.25), dec!(2544.31)),
+        (dec!(47), dec!(2.58), dec!(775.52)),
+        (dec!(45), dec!(5.54), dec!(9437.76)),
+        (dec!(43), dec!(6.01), dec!(6275.96)),
+        (dec!(39), dec!(5.36), dec!(4694.00)),
+        (dec!(38), dec!(2.39), dec!(8827.39)),
+        (dec!(37), dec!(0.83), dec!(19651.05)),
+        (dec!(37), dec!(4.90), dec!(12139.55)),
+        (dec!(36), dec!(1.67), dec!(12036.46)),
+        (dec!(35), dec!(1.84), dec!(2942.46)),
+        (dec!(33), dec!(0.24), dec!(7084.90)),
+        (dec!(32), dec!(0.18), dec!(5088.63)),
+        (dec!(32), dec!(1.78), dec!(398.15)),
+        (dec!(28), dec!(1.21), dec!(6286.60)),
+        (dec!(28), dec!(1.90), dec!(6279.55)),
+        (dec!(26), dec!(4.59), dec!(10447.39)),
+    ],
+    &[
+        (dec!(103019), dec!(1.107490), dec!(6283.075850)),
+        (dec!(1721), dec!(1.0644), dec!(12566.1517)),
+        (dec!(702), dec!(3.142), dec!(0)),
+        (dec!(32), dec!(1.02), dec!(18849.23)),
+        (dec!(31), dec!(2.84), dec!(5507.55)),
+        (dec!(25), dec!(1.32), dec!(5223.69)),
+        (dec!(18), dec!(1.42), dec!(1577.34)),
+        (dec!(10), dec!(5.91), dec!(10977.08)),
+        (dec!(9), dec!(1.42), dec!(6275.96)),
+        (dec!(9), dec!(0.27), dec!(5486.78)),
+    ],
+    &[
+        (dec!(4359), dec!(5.7846), dec!(6283.0758)),
+        (dec!(124), dec!(5.579), dec!(12566.152)),
+        (dec!(12), dec!(3.14), dec!(0)),
+        (dec!(9), dec!(3.63), dec!(77713.77)),
+        (dec!(6), dec!(1.87), dec!(5573.14)),
+        (dec!(3), dec!(5.47), dec!(18849.23)),
+    ],
+    &[
+        (dec!(145), dec!(4.273), dec!(6283.076)),
+        (dec!(7), dec!(3.92), dec!(12566.15)),
+    ],
+    &[
+        (dec!(4), dec!(2.56), dec!(6283.08)),
+    ],
+];
+
+const MARS_L: [&[VsopTerm]; 6] = [
+    &[
+        (dec!(620347712), dec!(0), dec!(0)),
+        (dec!(18656368), dec!(5.05037100), dec!(3340.61242670)),
+        (dec!(1108217), dec!(5.4009984), dec!(6681.2248534)),
+        (dec!(91798), dec!(5.75479), dec!(10021.83728)),
+        (dec!(27745), dec!(5.97050), dec!(3.52312)),
+        (dec!(12316), dec!(0.84956), dec!(2810.92146)),
+        (dec!(10610), dec!(2.93959), dec!(2281.23050)),
+        (dec!(8927), dec!(4.1570), dec!(0.0173)),
+        (dec!(8716), dec!(6.1101), dec!(13362.4497)),
+        (dec!(7775), dec!(3.3397), dec!(5621.8429)),
+        (dec!(6798), dec!(0.3646), dec!(398.1490)),
+        (dec!(4161), dec!(0.2281), dec!(2942.4634)),
+        (dec!(3575), dec!(1.6619), dec!(2544.3144)),
+        (dec!(3075), dec!(0.8570), dec!(191.4483)),
+        (dec!(2938), dec!(6.0789), dec!(0.0673)),
+        (dec!(2628), dec!(0.6481), dec!(3337.0893)),
+        (dec!(2580), dec!(0.0300), dec!(3344.1355)),
+        (dec!(2389), dec!(5.0390), dec!(796.2980)),
+        (dec!(1799), dec!(0.6563), dec!(529.6910)),
+        (dec!(1546), dec!(2.9158), dec!(1751.5395)),
+        (dec!(1528), dec!(1.1498), dec!(6151.5339)),
+        (dec!(1286), dec!(3.0680), dec!(2146.1654)),
+        (dec!(1264), dec!(3.6228), dec!(5092.1520)),
+        (dec!(1025), dec!(3.6933), dec!(8962.4553)),
+        (dec!(892), dec!(0.183), dec!(16703.062)),
+        (dec!(859), dec!(2.401), dec!(2914.014)),
+        (dec!(833), dec!(4.495), dec!(3340.630)),
+        (dec!(833), dec!(2.464), dec!(3340.595)),
+        (dec!(749), dec!(3.822), dec!(155.420)),
+        (dec!(724), dec!(0.675), dec!(3738.761)),
+        (dec!(713), dec!(3.663), dec!(1059.382)),
+        (dec!(655), dec!(0.489), dec!(3127.313)),
+        (dec!(636), dec!(2.922), dec!(8432.764)),
+        (dec!(553), dec!(4.475), dec!(1748.016)),
+        (dec!(550), dec!(3.810), dec!(0.980)),
+        (dec!(472), dec!(3.625), dec!(1194.447)),
+        (dec!(426), dec!(0.554), dec!(6283.076)),
+        (dec!(415), dec!(0.497), dec!(213.299)),
+        (dec!(312), dec!(0.999), dec!(6677.702)),
+        (dec!(307), dec!(0.381), dec!(6684.748)),
+        (dec!(302), dec!(4.486), dec!(3532.061)),
+        (dec!(299), dec!(2.783), dec!(6254.627)),
+        (dec!(293), dec!(4.221), dec!(20.775)),
+        (dec!(284), dec!(5.769), dec!(3149.164)),
+        (dec!(281), dec!(5.882), dec!(1349.867)),
+        (dec!(274), dec!(0.542), dec!(3340.545)),
+        (dec!(274), dec!(0.134), dec!(3340.680)),
+        (dec!(239), dec!(5.372), dec!(4136.910)),
+        (dec!(236), dec!(5.755), dec!(3333.499)),
+        (dec!(231), dec!(1.282), dec!(3870.303)),
+        (dec!(221), dec!(3.505), dec!(382.897)),
+        (dec!(204), dec!(2.821), dec!(1221.849)),
+        (dec!(193), dec!(3.357), dec!(3.590)),
+        (dec!(189), dec!(1.491), dec!(9492.146)),
+        (dec!(179), dec!(1.006), dec!(951.718)),
+        (dec!(174), dec!(2.414), dec!(553.569)),
+        (dec!(172), dec!(0.439), dec!(5486.778)),
+        (dec!(160), dec!(3.949), dec!(4562.461)),
+        (dec!(144), dec!(1.419), dec!(135.065)),
+        (dec!(140), dec!(3.326), dec!(2700.715)),
+        (dec!(138), dec!(4.301), dec!(7.114)),
+        (dec!(131), dec!(4.045), dec!(12303.068)),
+        (dec!(128), dec!(2.208), dec!(1592.596)),
+        (dec!(128), dec!(1.807), dec!(5088.629)),
+        (dec!(117), dec!(3.128), dec!(7903.073)),
+        (dec!(113), dec!(3.701), dec!(1589.073)),
+        (dec!(110), dec!(1.052), dec!(242.729)),
+        (dec!(105), dec!(0.785), dec!(8827.390)),
+        (dec!(100), dec!(3.243), dec!(11773.377)),
+    ],
+    &[
+        (dec!(334085627474), dec!(0), dec!(0)),
+        (dec!(1458227), dec!(3.6042605), dec!(3340.6124267)),
+        (dec!(164901), dec!(3.926313), dec!(6681.224853)),
+        (dec!(19963), dec!(4.26594), dec!(10021.83728)),
+        (dec!(3452), dec!(4.7321), dec!(3.5231)),
+        (dec!(2485), dec!(4.6128), dec!(13362.4497)),
+        (dec!(842), dec!(4.459), dec!(2281.230)),
+        (dec!(538), dec!(5.016), dec!(398.149)),
+        (dec!(521), dec!(4.994), dec!(3344.136)),
+        (dec!(433), dec!(2.561), dec!(191.448)),
+        (dec!(430), dec!(5.316), dec!(155.420)),
+        (dec!(382), dec!(3.539), dec!(796.298)),
+        (dec!(314), dec!(4.963), dec!(16703.062)),
+        (dec!(283), dec!(3.160), dec!(2544.314)),
+        (dec!(206), dec!(4.569), dec!(2146.165)),
+        (dec!(169), dec!(1.329), dec!(3337.089)),
+        (dec!(158), dec!(4.185), dec!(1751.540)),
+        (dec!(134), dec!(2.233), dec!(0.980)),
+        (dec!(134), dec!(5.974), dec!(1748.016)),
+        (dec!(118), dec!(6.024), dec!(6151.534)),
+        (dec!(117), dec!(2.213), dec!(1059.382)),
+        (dec!(114), dec!(2.129), dec!(1194.447)),
+        (dec!(114), dec!(5.428), dec!(3738.761)),
+        (dec!(91), dec!(1.10), dec!(1349.87)),
+        (dec!(85), dec!(3.91), dec!(553.57)),
+        (dec!(83), dec!(5.30), dec!(6684.75)),
+        (dec!(81), dec!(4.43), dec!(529.69)),
+        (dec!(80), dec!(2.25), dec!(8962.46)),
+        (dec!(73), dec!(2.50), dec!(951.72)),
+        (dec!(73), dec!(5.84), dec!(242.73)),
+        (dec!(71), dec!(3.86), dec!(2914.01)),
+        (dec!(68), dec!(5.02), dec!(382.90)),
+        (dec!(65), dec!(1.02), dec!(3340.60)),
+        (dec!(65), dec!(3.05), dec!(3340.63)),
+        (dec!(62), dec!(4.15), dec!(3149.16)),
+        (dec!(57), dec!(3.89), dec!(4136.91)),
+        (dec!(48), dec!(4.87), dec!(213.30)),
+        (dec!(48), dec!(1.18), dec!(3333.50)),
+        (dec!(47), dec!(1.31), dec!(3185.19)),
+        (dec!(41), dec!(0.71), dec!(1592.60)),
+        (dec!(40), dec!(2.73), dec!(7.11)),
+        (dec!(40), dec!(5.32), dec!(20043.67)),
+        (dec!(33), dec!(5.41), dec!(6283.08)),
+        (dec!(28), dec!(0.05), dec!(9492.15)),
+        (dec!(27), dec!(3.89), dec!(1221.85)),
+        (dec!(27), dec!(5.11), dec!(2700.72)),
+    ],
+    &[
+        (dec!(58016), dec!(2.04979), dec!(3340.61243)),
+        (dec!(54188), dec!(0), dec!(0)),
+        (dec!(13908), dec!(2.45742), dec!(6681.22485)),
+        (dec!(2465), dec!(2.8000), dec!(10021.8373)),
+        (dec!(398), dec!(3.141), dec!(13362.450)),
+        (dec!(222), dec!(3.194), dec!(3.523)),
+        (dec!(121), dec!(0.543), dec!(155.420)),
+        (dec!(62), dec!(3.49), dec!(16703.06)),
+        (dec!(54), dec!(3.54), dec!(3344.14)),
+        (dec!(34), dec!(6.00), dec!(2281.23)),
+        (dec!(32), dec!(4.14), dec!(191.45)),
+        (dec!(30), dec!(2.00), dec!(796.30)),
+        (dec!(23), dec!(4.33), dec!(242.73)),
+        (dec!(22), dec!(3.45), dec!(398.15)),
+        (dec!(20), dec!(5.42), dec!(553.57)),
+        (dec!(16), dec!(0.66), dec!(0.98)),
+        (dec!(16), dec!(6.11), dec!(2146.17)),
+        (dec!(16), dec!(1.22), dec!(1748.02)),
+        (dec!(15), dec!(6.10), dec!(3185.19)),
+        (dec!(14), dec!(4.02), dec!(951.72)),
+        (dec!(14), dec!(2.62), dec!(1349.87)),
+        (dec!(13), dec!(0.60), dec!(1194.45)),
+        (dec!(12), dec!(3.86), dec!(6684.75)),
+        (dec!(11), dec!(4.72), dec!(2544.31)),
+        (dec!(10), dec!(0.25), dec!(382.90)),
+        (dec!(9), dec!(0.68), dec!(1059.38)),
+        (dec!(9), dec!(3.83), dec!(20043.67)),
+        (dec!(9), dec!(3.88), dec!(3738.76)),
+        (dec!(8), dec!(5.46), dec!(1751.54)),
+        (dec!(7), dec!(2.58), dec!(3149.16)),
+        (dec!(7), dec!(2.38), dec!(4136.91)),
+        (dec!(6), dec!(5.48), dec!(1592.60)),
+        (dec!(6), dec!(2.34), dec!(3097.88)),
+    ],
+    &[
+        (dec!(1482), dec!(0.4443), dec!(3340.6124)),
+        (dec!(662), dec!(0.885), dec!(6681.225)),
+        (dec!(188), dec!(1.288), dec!(10021.837)),
+        (dec!(41), dec!(1.65), dec!(13362.45)),
+        (dec!(26), dec!(0), dec!(0)),
+        (dec!(23), dec!(2.05), dec!(155.42)),
+        (dec!(10), dec!(1.58), dec!(3.52)),
+        (dec!(8), dec!(2.00), dec!(16703.06)),
+        (dec!(5), dec!(2.82), dec!(242.73)),
+        (dec!(4), dec!(2.02), dec!(3344.14)),
+        (dec!(3), dec!(4.59), dec!(3185.19)),
+        (dec!(3), dec!(0.65), dec!(553.57)),
+    ],
+    &[
+        (dec!(114), dec!(3.1416), dec!(0)),
+        (dec!(29), dec!(5.64), dec!(6681.22)),
+        (dec!(24), dec!(5.14), dec!(3340.61)),
+        (dec!(11), dec!(6.03), dec!(10021.84)),
+        (dec!(3), dec!(0.13), dec!(13362.45)),
+        (dec!(3), dec!(3.56), dec!(155.42)),
+        (dec!(1), dec!(0.49), dec!(16703.06)),
+        (dec!(1), dec!(1.32), dec!(242.73)),
+    ],
+    &[
+        (dec!(1), dec!(3.14), dec!(0)),
+        (dec!(1), dec!(4.04), dec!(6681.22)),
+        (dec!(1), dec!(4.49), dec!(3340.61)),
+    ],
+];
+
+const MARS_B: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(3197135), dec!(3.7683204), dec!(3340.6124267)),
+        (dec!(298033), dec!(4.106170), dec!(6681.224853)),
+        (dec!(289105), dec!(0), dec!(0)),
+        (dec!(31366), dec!(4.44651), dec!(10021.83728)),
+        (dec!(3484), dec!(4.7881), dec!(13362.4497)),
+        (dec!(443), dec!(5.026), dec!(3344.136)),
+        (dec!(443), dec!(5.652), dec!(3337.089)),
+        (dec!(399), dec!(5.131), dec!(16703.062)),
+        (dec!(293), dec!(3.793), dec!(2281.230)),
+        (dec!(182), dec!(6.136), dec!(6151.534)),
+        (dec!(163), dec!(4.264), dec!(529.691)),
+        (dec!(160), dec!(2.232), dec!(1059.382)),
+        (dec!(149), dec!(2.165), dec!(5621.843)),
+        (dec!(143), dec!(1.182), dec!(3340.595)),
+        (dec!(143), dec!(3.213), dec!(3340.630)),
+        (dec!(139), dec!(2.418), dec!(8962.455)),
+    ],
+    &[
+        (dec!(350069), dec!(5.368478), dec!(3340.612427)),
+        (dec!(14116), dec!(3.14159), dec!(0)),
+        (dec!(9671), dec!(5.4788), dec!(6681.2249)),
+        (dec!(1472), dec!(3.2021), dec!(10021.8373)),
+        (dec!(426), dec!(3.408), dec!(13362.450)),
+        (dec!(102), dec!(0.776), dec!(3337.089)),
+        (dec!(79), dec!(3.72), dec!(16703.06)),
+        (dec!(33), dec!(3.46), dec!(5621.84)),
+        (dec!(26), dec!(2.48), dec!(2281.23)),
+    ],
+    &[
+        (dec!(16727), dec!(0.60221), dec!(3340.61243)),
+        (dec!(4987), dec!(3.1416), dec!(0)),
+        (dec!(302), dec!(5.559), dec!(6681.225)),
+        (dec!(26), dec!(1.90), dec!(13362.45)),
+        (dec!(21), dec!(0.92), dec!(10021.84)),
+        (dec!(12), dec!(2.24), dec!(3340.63)),
+        (dec!(8), dec!(2.25), dec!(16703.06)),
+    ],
+    &[
+        (dec!(607), dec!(1.981), dec!(3340.612)),
+        (dec!(43), dec!(0), dec!(0)),
+        (dec!(14), dec!(1.80), dec!(6681.22)),
+        (dec!(3), dec!(3.45), dec!(10021.84)),
+    ],
+    &[
+        (dec!(13), dec!(0), dec!(0)),
+        (dec!(11), dec!(3.46), dec!(3340.61)),
+        (dec!(1), dec!(0.50), dec!(6681.22)),
+    ],
+];
+
+const MARS_R: [&[VsopTerm]; 5] = [
+    &[
+        (dec!(153033488), dec!(0), dec!(0)),
+        (dec!(14184953), dec!(3.47971284), dec!(3340.61242670)),
+        (dec!(660776), dec!(3.817834), dec!(6681.224853)),
+        (dec!(46179), dec!(4.15595), dec!(10021.83728)),
+        (dec!(8110), dec!(5.5596), dec!(2810.9215)),
+        (dec!(7485), dec!(1.7724), dec!(5621.8429)),
+        (dec!(5523), dec!(1.3644), dec!(2281.2305)),
+        (dec!(3825), dec!(4.4941), dec!(13362.4497)),
+        (dec!(2484), dec!(4.9255), dec!(2942.4634)),
+        (dec!(2307), dec!(0.0908), dec!(2544.3144)),
+        (dec!(1999), dec!(5.3606), dec!(3337.0893)),
+        (dec!(1960), dec!(4.7425), dec!(3344.1355)),
+        (dec!(1167), dec!(2.1126), dec!(5092.1520)),
+        (dec!(1103), dec!(5.0091), dec!(398.1490)),
+        (dec!(992), dec!(5.839), dec!(6151.534)),
+        (dec!(899), dec!(4.408), dec!(529.691)),
+        (dec!(807), dec!(2.102), dec!(1059.382)),
+        (dec!(798), dec!(3.448), dec!(796.298)),
+        (dec!(741), dec!(1.499), dec!(2146.165)),
+        (dec!(726), dec!(1.245), dec!(8432.764)),
+        (dec!(692), dec!(2.134), dec!(8962.455)),
+        (dec!(633), dec!(0.894), dec!(3340.595)),
+        (dec!(633), dec!(2.924), dec!(3340.630)),
+        (dec!(630), dec!(1.287), dec!(1751.540)),
+        (dec!(574), dec!(0.829), dec!(2914.014)),
+        (dec!(526), dec!(5.383), dec!(3738.761)),
+        (dec!(473), dec!(5.199), dec!(3127.313)),
+        (dec!(348), dec!(4.832), dec!(16703.062)),
+        (dec!(284), dec!(2.907), dec!(3532.061)),
+        (dec!(280), dec!(5.257), dec!(6283.076)),
+        (dec!(276), dec!(1.218), dec!(6254.627)),
+        (dec!(275), dec!(2.908), dec!(1748.016)),
+        (dec!(270), dec!(3.764), dec!(5884.927)),
+        (dec!(239), dec!(2.037), dec!(1194.447)),
+        (dec!(234), dec!(5.105), dec!(5486.778)),
+        (dec!(228), dec!(3.255), dec!(6872.673)),
+        (dec!(223), dec!(4.199), dec!(3149.164)),
+        (dec!(219), dec!(5.583), dec!(191.448)),
+        (dec!(208), dec!(5.255), dec!(3340.545)),
+        (dec!(208), dec!(4.846), dec!(3340.680)),
+        (dec!(186), dec!(5.699), dec!(6677.702)),
+        (dec!(183), dec!(5.081), dec!(6684.748)),
+        (dec!(179), dec!(4.184), dec!(3333.499)),
+        (dec!(176), dec!(5.953), dec!(3870.303)),
+        (dec!(164), dec!(3.799), dec!(4136.910)),
+    ],
+    &[
+        (dec!(1107433), dec!(2.0325052), dec!(3340.6124267)),
+        (dec!(103176), dec!(2.370718), dec!(6681.224853)),
+        (dec!(12877), dec!(0), dec!(0)),
+        (dec!(10816), dec!(2.70888), dec!(10021.83728)),
+        (dec!(1195), dec!(3.0470), dec!(13362.4497)),
+        (dec!(439), dec!(2.888), dec!(2281.230)),
+        (dec!(396), dec!(3.423), dec!(3344.136)),
+        (dec!(183), dec!(1.584), dec!(2544.314)),
+        (dec!(136), dec!(3.385), dec!(16703.062)),
+        (dec!(128), dec!(6.043), dec!(3337.089)),
+        (dec!(128), dec!(0.630), dec!(1059.382)),
+        (dec!(127), dec!(1.954), dec!(796.298)),
+        (dec!(118), dec!(2.998), dec!(2146.165)),
+        (dec!(88), dec!(3.42), dec!(398.15)),
+        (dec!(83), dec!(3.86), dec!(3738.76)),
+        (dec!(76), dec!(4.45), dec!(6151.53)),
+        (dec!(72), dec!(2.76), dec!(529.69)),
+        (dec!(67), dec!(2.55), dec!(1751.54)),
+        (dec!(66), dec!(4.41), dec!(1748.02)),
+        (dec!(58), dec!(0.54), dec!(1194.45)),
+        (dec!(54), dec!(0.68), dec!(8962.46)),
+        (dec!(51), dec!(3.73), dec!(6684.75)),
+        (dec!(49), dec!(5.73), dec!(3340.60)),
+        (dec!(49), dec!(1.48), dec!(3340.63)),
+        (dec!(48), dec!(2.58), dec!(3149.16)),
+        (dec!(48), dec!(2.29), dec!(2914.01)),
+        (dec!(39), dec!(2.32), dec!(4136.91)),
+    ],
+    &[
+        (dec!(44242), dec!(0.47931), dec!(3340.61243)),
+        (dec!(8138), dec!(0.8700), dec!(6681.2249)),
+        (dec!(1275), dec!(1.2259), dec!(10021.8373)),
+        (dec!(187), dec!(1.573), dec!(13362.450)),
+        (dec!(52), dec!(3.14), dec!(0)),
+        (dec!(41), dec!(1.97), dec!(3344.14)),
+        (dec!(27), dec!(1.92), dec!(16703.06)),
+        (dec!(18), dec!(4.43), dec!(2281.23)),
+        (dec!(12), dec!(4.53), dec!(3185.19)),
+        (dec!(10), dec!(5.39), dec!(1059.38)),
+        (dec!(10), dec!(0.42), dec!(796.30)),
+    ],
+    &[
+        (dec!(1113), dec!(5.1499), dec!(3340.6124)),
+        (dec!(424), dec!(5.613), dec!(6681.225)),
+        (dec!(100), dec!(5.997), dec!(10021.837)),
+        (dec!(20), dec!(0.08), dec!(13362.45)),
+        (dec!(5), dec!(3.14), dec!(0)),
+        (dec!(3), dec!(0.43), dec!(16703.06)),
+    ],
+    &[
+        (dec!(20), dec!(3.58), dec!(3340.61)),
+        (dec!(16), dec!(4.05), dec!(6681.22)),
+        (dec!(6), dec!(4.46), dec!(10021.84)),
+        (dec!(2), dec!(4.84), dec!(13362.45)),
+    ],
+];
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Earth,
+    Mars,
+}
+
+/// Returns the heliocentric ``(longitude, latitude, radius vector)`` of a planet, referred to the mean ecliptic and equinox of the date.\
+/// The longitude lies between 0 and 360 and the latitude between -90 and 90, both in degrees. The radius vector is in AU.
+/// 
+/// Taken from "Positions of the Planets", using the VSOP87 series truncated as in Appendix III.\
+/// The positions are accurate to a few arcseconds over several millennia around J2000.0, which is ample for finder charts.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn planet_heliocentric(planet: Planet, jd: JulianDay) -> (Decimal, Decimal, Decimal) {
+    let (l, b, r) = match planet {
+        Planet::Mercury => (&MERCURY_L[..], &MERCURY_B[..], &MERCURY_R[..]),
+        Planet::Venus => (&VENUS_L[..], &VENUS_B[..], &VENUS_R[..]),
+        Planet::Earth => (&EARTH_L[..], &EARTH_B[..], &EARTH_R[..]),
+        Planet::Mars => (&MARS_L[..], &MARS_B[..], &MARS_R[..]),
+    };
+
+    let tau = jd.millennia_since_j2000();
+
+    (normalize_degrees(to_degrees(vsop(l, tau))), to_degrees(vsop(b, tau)), vsop(r, tau))
+}
+
+/// Returns the elongation of a planet in degrees, the angular separation between the Sun and the planet as seen from the Earth.\
+/// The elongation is positive when the planet lies east of the Sun (an evening object) and negative when it lies west (a morning object).
+/// 
+/// Taken from "Positions of the Planets" and "Illuminated Fraction of the Disk of a Planet", ignoring light-time and aberration.
+/// 
+/// Returns ``None`` for ``Planet::Earth``, whose elongation is undefined.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn sun_planet_elongation(planet: Planet, jd: JulianDay) -> Option<Decimal> {
+    if planet == Planet::Earth {
+        return None;
+    }
+
+    let (l, b, r) = planet_heliocentric(planet, jd);
+    let (l0, b0, r0) = planet_heliocentric(Planet::Earth, jd);
+
+    // Geocentric rectangular ecliptic coordinates of the planet
+    let x = r * cos_deg(b) * cos_deg(l) - r0 * cos_deg(b0) * cos_deg(l0);
+    let y = r * cos_deg(b) * sin_deg(l) - r0 * cos_deg(b0) * sin_deg(l0);
+    let z = r * sin_deg(b) - r0 * sin_deg(b0);
+
+    let lambda = atan2_deg(y, x);
+    let beta = atan2_deg(z, (x * x + y * y).sqrt().unwrap());
+
+    // The Sun is seen from the Earth in the direction opposite to the Earth's heliocentric position
+    let (lambda0, beta0) = (l0 + dec!(180), -b0);
+
+    let elongation = acos_deg(sin_deg(beta) * sin_deg(beta0) + cos_deg(beta) * cos_deg(beta0) * cos_deg(lambda - lambda0));
+    if normalize_degrees(lambda - lambda0) <= dec!(180) {
+        Some(elongation)
+    } else {
+        Some(-elongation)
+    }
+}
+
+/// Evaluates a VSOP87 series ``Σ Xₙ τⁿ``, where each ``Xₙ`` is a sum of periodic terms.
+fn vsop(series: &[&[VsopTerm]], tau: Decimal) -> Decimal {
+    let sum: Decimal = series.iter()
+        .rev()
+        .fold(Decimal::ZERO, |acc, terms| {
+            let x: Decimal = terms.iter()
+                .map(|&(a, b, c)| a * ((b + c * tau) % Decimal::TWO_PI).cos())
+                .sum();
+            acc * tau + x
+        });
+
+    sum / dec!(100000000)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::planets::*;
+
+    #[test]
+    fn test_sun_planet_elongation() {
+        // Venus in the evening sky on 1992 December 20.0 TD, the date of Example 32.a
+        let elongation = sun_planet_elongation(Planet::Venus, JulianDay::new(dec!(2448976.5))).unwrap();
+        assert!((elongation - dec!(44.764)).abs() < dec!(0.001));
+
+        // Venus at its greatest western elongation on 2023 October 23, in the morning sky
+        let elongation = sun_planet_elongation(Planet::Venus, JulianDay::new(dec!(2460240.5))).unwrap();
+        assert!((elongation - dec!(-46.41)).abs() < dec!(0.01));
+
+        // Mercury at its greatest western elongation on 2024 May 9
+        let elongation = sun_planet_elongation(Planet::Mercury, JulianDay::new(dec!(2460439.5))).unwrap();
+        assert!((elongation - dec!(-26.4)).abs() < dec!(0.1));
+
+        // Mars at opposition on 2020 October 13
+        let elongation = sun_planet_elongation(Planet::Mars, JulianDay::new(dec!(2459135.5))).unwrap();
+        assert!(elongation.abs() > dec!(175));
+
+        assert_eq!(sun_planet_elongation(Planet::Earth, JulianDay::J2000), None);
+    }
+
+    #[test]
+    fn test_venus() {
+        // Example 32.a, 1992 December 20.0 TD
+        let (l, b, r) = planet_heliocentric(Planet::Venus, JulianDay::new(dec!(2448976.5)));
+        assert!((l - dec!(26.11428)).abs() < dec!(0.00001));
+        assert!((b - dec!(-2.62070)).abs() < dec!(0.00001));
+        assert!((r - dec!(0.724603)).abs() < dec!(0.000001));
+    }
+
+    #[test]
+    fn test_earth() {
+        // Example 25.b, 1992 October 13.0 TD
+        let (l, b, r) = planet_heliocentric(Planet::Earth, JulianDay::new(dec!(2448908.5)));
+        assert!((l - dec!(19.907372)).abs() < dec!(0.000001));
+        assert!((b - dec!(-0.000179)).abs() < dec!(0.000001));
+        assert!((r - dec!(0.99760775)).abs() < dec!(0.00000001));
+    }
+
+    #[test]
+    fn test_mercury() {
+        // 2000 January 1.5 TD, checked against the complete VSOP87D series of Bretagnon and Francou:
+        // L = 4.4293481036 rad, B = -0.0527573409 rad, R = 0.4664714751
+        let (l, b, r) = planet_heliocentric(Planet::Mercury, JulianDay::J2000);
+        assert!((l - dec!(253.78295)).abs() < dec!(0.0001));
+        assert!((b - dec!(-3.02277)).abs() < dec!(0.0001));
+        assert!((r - dec!(0.466471)).abs() < dec!(0.000002));
+    }
+
+    #[test]
+    fn test_mars() {
+        // 2000 January 1.5 TD, checked against the complete VSOP87D series of Bretagnon and Francou:
+        // L = 6.2735389983 rad, B = -0.0247779824 rad, R = 1.3912076925
+        let (l, b, r) = planet_heliocentric(Planet::Mars, JulianDay::J2000);
+        assert!((l - dec!(359.44731)).abs() < dec!(0.0003));
+        assert!((b - dec!(-1.41967)).abs() < dec!(0.0002));
+        assert!((r - dec!(1.391208)).abs() < dec!(0.000005));
+    }
+}
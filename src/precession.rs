@@ -0,0 +1,53 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{asin_deg, atan2_deg, cos_deg, normalize_degrees, sin_deg};
+use crate::julian::JulianDay;
+
+/// Precesses the equatorial coordinates ``(right ascension, declination)`` in degrees from the epoch ``from`` to the epoch ``to``.\
+/// The right ascension lies between 0 and 360, the declination between -90 and 90.
+/// 
+/// Taken from "Precession", using the rigorous method.
+/// 
+/// **NOTE:** The coordinates are referred to the mean equator and equinox of each epoch.
+/// Any proper motion of the object should be applied beforehand.
+pub fn precess_equatorial(ra: Decimal, dec: Decimal, from: JulianDay, to: JulianDay) -> (Decimal, Decimal) {
+    let big_t = from.centuries_since_j2000();
+    let t = (to - from) / dec!(36525);
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    // The precession angles, in arcseconds
+    let base = dec!(2306.2181) + dec!(1.39656) * big_t - dec!(0.000139) * big_t * big_t;
+    let zeta = base * t + (dec!(0.30188) - dec!(0.000344) * big_t) * t2 + dec!(0.017998) * t3;
+    let z = base * t + (dec!(1.09468) + dec!(0.000066) * big_t) * t2 + dec!(0.018203) * t3;
+    let theta = (dec!(2004.3109) - dec!(0.85330) * big_t - dec!(0.000217) * big_t * big_t) * t
+        - (dec!(0.42665) + dec!(0.000217) * big_t) * t2
+        - dec!(0.041833) * t3;
+
+    let (zeta, z, theta) = (zeta / dec!(3600), z / dec!(3600), theta / dec!(3600));
+
+    let a = cos_deg(dec) * sin_deg(ra + zeta);
+    let b = cos_deg(theta) * cos_deg(dec) * cos_deg(ra + zeta) - sin_deg(theta) * sin_deg(dec);
+    let c = sin_deg(theta) * cos_deg(dec) * cos_deg(ra + zeta) + cos_deg(theta) * sin_deg(dec);
+
+    (normalize_degrees(atan2_deg(a, b) + z), asin_deg(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::precession::*;
+
+    #[test]
+    fn test_precess_equatorial() {
+        // Example 21.b, θ Persei from J2000.0 to 2028 November 13.19 TD, with its proper motion already applied
+        let (ra, dec) = precess_equatorial(dec!(41.054063), dec!(49.227750), JulianDay::J2000, JulianDay::new(dec!(2462088.69)));
+        assert!((ra - dec!(41.547214)).abs() < dec!(0.000002));
+        assert!((dec - dec!(49.348483)).abs() < dec!(0.000002));
+
+        // Precessing back returns the original coordinates
+        let (ra, dec) = precess_equatorial(ra, dec, JulianDay::new(dec!(2462088.69)), JulianDay::J2000);
+        assert!((ra - dec!(41.054063)).abs() < dec!(0.000001));
+        assert!((dec - dec!(49.227750)).abs() < dec!(0.000001));
+    }
+}
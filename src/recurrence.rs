@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+use rust_decimal::Decimal;
+
+use crate::julian::{CalendarDate, WeekDay};
+
+/// A rule describing how an event repeats, used by ``recurrences``.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecurrenceRule {
+    /// Every ``n`` days.
+    EveryDays(u32),
+    /// Every ``n`` weeks on the given day of the week.
+    EveryWeeks(u32, WeekDay),
+    /// Every month on the given day of the month, between 1 and 31, clamped to the last day of shorter months.
+    MonthlyOnDay(u8),
+}
+
+/// Returns the first ``count`` occurrences of ``rule``, starting on or after ``start``.
+/// 
+/// The time of day of ``start`` is kept for every occurrence.
+/// - ``EveryDays`` starts on ``start`` itself
+/// - ``EveryWeeks`` starts on the first matching day of the week, which may be ``start`` itself
+/// - ``MonthlyOnDay`` starts in the month of ``start`` if that day has not passed yet, e.g. the 31st
+///   recurs on 2023 January 31st, February 28th, March 31st, April 30th...
+/// 
+/// Returns no occurrences for ``MonthlyOnDay`` with a day outside of 1 to 31.
+/// 
+/// **NOTE:** Dates follow ``CalendarDate::add_days`` and ``CalendarDate::add_months``, so a result landing
+/// on one of the days dropped in 1582 October moves past the Gregorian reform.
+pub fn recurrences(start: CalendarDate, rule: RecurrenceRule, count: usize) -> Vec<CalendarDate> {
+    match rule {
+        RecurrenceRule::EveryDays(n) => {
+            (0..count)
+                .map(|i| start.add_days(Decimal::from(i) * Decimal::from(n)))
+                .collect()
+        },
+        RecurrenceRule::EveryWeeks(n, weekday) => {
+            let first = start.add_days(Decimal::from(start.days_until(weekday)));
+            (0..count)
+                .map(|i| first.add_days(Decimal::from(i) * Decimal::from(n) * Decimal::from(7)))
+                .collect()
+        },
+        RecurrenceRule::MonthlyOnDay(day) => {
+            if !(1..=31).contains(&day) {
+                return Vec::new();
+            }
+
+            // Every occurrence is derived from the unclamped day, so that clamping in February does not carry over to March
+            let base = start.with_day(Decimal::from(day) + start.day().fract());
+            let skip = if base.add_months(0) < start { 1 } else { 0 };
+
+            (0..count)
+                .map(|i| base.add_months(i32::try_from(i).unwrap() + skip))
+                .collect()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use crate::recurrence::*;
+
+    #[test]
+    fn test_every_days() {
+        let start = CalendarDate::new(2000, 2, dec!(27.5));
+        assert_eq!(recurrences(start, RecurrenceRule::EveryDays(2), 3), vec![
+            CalendarDate::new(2000, 2, dec!(27.5)),
+            CalendarDate::new(2000, 2, dec!(29.5)),
+            CalendarDate::new(2000, 3, dec!(2.5)),
+        ]);
+
+        assert!(recurrences(start, RecurrenceRule::EveryDays(2), 0).is_empty());
+    }
+
+    #[test]
+    fn test_every_weeks() {
+        // 2024 January 1st was a Monday, the first Friday follows four days later
+        let start = CalendarDate::new(2024, 1, dec!(1));
+        assert_eq!(recurrences(start, RecurrenceRule::EveryWeeks(2, WeekDay::Friday), 3), vec![
+            CalendarDate::new(2024, 1, dec!(5)),
+            CalendarDate::new(2024, 1, dec!(19)),
+            CalendarDate::new(2024, 2, dec!(2)),
+        ]);
+
+        assert_eq!(recurrences(start, RecurrenceRule::EveryWeeks(1, WeekDay::Monday), 2), vec![
+            CalendarDate::new(2024, 1, dec!(1)),
+            CalendarDate::new(2024, 1, dec!(8)),
+        ]);
+    }
+
+    #[test]
+    fn test_monthly_on_day() {
+        let start = CalendarDate::new(2023, 1, dec!(31));
+        assert_eq!(recurrences(start, RecurrenceRule::MonthlyOnDay(31), 5), vec![
+            CalendarDate::new(2023, 1, dec!(31)),
+            CalendarDate::new(2023, 2, dec!(28)),
+            CalendarDate::new(2023, 3, dec!(31)),
+            CalendarDate::new(2023, 4, dec!(30)),
+            CalendarDate::new(2023, 5, dec!(31)),
+        ]);
+
+        // The 10th has already passed in the starting month
+        let start = CalendarDate::new(2023, 12, dec!(15));
+        assert_eq!(recurrences(start, RecurrenceRule::MonthlyOnDay(10), 2), vec![
+            CalendarDate::new(2024, 1, dec!(10)),
+            CalendarDate::new(2024, 2, dec!(10)),
+        ]);
+
+        assert!(recurrences(start, RecurrenceRule::MonthlyOnDay(0), 2).is_empty());
+        assert!(recurrences(start, RecurrenceRule::MonthlyOnDay(32), 2).is_empty());
+    }
+}
@@ -0,0 +1,268 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{acos_deg, asin_deg, cos_deg, normalize_degrees, sin_deg};
+use crate::dynamical::delta_t;
+use crate::interpolation::interpolate3;
+use crate::julian::{CalendarDate, JulianDay};
+use crate::moon::{moon_apparent_equatorial, moon_position};
+use crate::sidereal::greenwich_mean_sidereal_time;
+use crate::sun::sun_apparent_equatorial;
+
+/// The standard altitude of the Sun's center at sunrise and sunset, accounting for refraction and the solar semi-diameter.
+const SUN_STANDARD_ALTITUDE: Decimal = dec!(-0.8333);
+
+/// The standard altitude of a star or planet at rising and setting, accounting for refraction.
+const STAR_STANDARD_ALTITUDE: Decimal = dec!(-0.5667);
+
+/// Returns the instants of ``(sunrise, sunset)`` in Universal Time on the given date, for an observer at
+/// ``latitude`` (positive north) and ``longitude`` (positive east), both in degrees.
+/// 
+/// Taken from "Rising, Transit, and Setting"
+/// 
+/// Returns ``None`` during polar day or night, when the Sun does not cross the horizon.
+/// 
+/// **NOTE:** Only the date of ``date`` is used, the fractional part of the day is ignored.
+/// Both events fall within the Universal Time day, so far from Greenwich the sunset may precede the sunrise.\
+/// Results are accurate to within a minute between +/- 72 degrees of latitude, and within 10 minutes beyond that.
+pub fn sunrise_sunset(date: CalendarDate, latitude: Decimal, longitude: Decimal) -> Option<(JulianDay, JulianDay)> {
+    let jd0 = date.julian_day_at_midnight();
+
+    let positions = [
+        sun_apparent_equatorial(jd0 - Decimal::ONE),
+        sun_apparent_equatorial(jd0),
+        sun_apparent_equatorial(jd0 + Decimal::ONE),
+    ];
+
+    let events = rise_transit_set(jd0, date.year(), positions, SUN_STANDARD_ALTITUDE, latitude, longitude)?;
+    Some((events.rise, events.set))
+}
+
+/// Returns the instants of ``(moonrise, transit, moonset)`` in Universal Time on the given date, for an observer at
+/// ``latitude`` (positive north) and ``longitude`` (positive east), both in degrees.
+/// 
+/// Taken from "Rising, Transit, and Setting", with the standard altitude derived from the Moon's horizontal parallax.
+/// 
+/// As the Moon rises about 50 minutes later every day, roughly once a month one of its events does not happen within the Universal Time day,
+/// and that event alone is ``None``. Returns ``None`` if the Moon does not cross the horizon at all.
+/// 
+/// **NOTE:** Only the date of ``date`` is used, the fractional part of the day is ignored.\
+/// Results are accurate to within a few minutes.
+pub fn moonrise_moonset(date: CalendarDate, latitude: Decimal, longitude: Decimal) -> Option<(Option<JulianDay>, Option<JulianDay>, Option<JulianDay>)> {
+    let jd0 = date.julian_day_at_midnight();
+
+    let positions = [
+        moon_apparent_equatorial(jd0 - Decimal::ONE),
+        moon_apparent_equatorial(jd0),
+        moon_apparent_equatorial(jd0 + Decimal::ONE),
+    ];
+
+    let (_, _, distance) = moon_position(jd0);
+    let parallax = asin_deg(dec!(6378.14) / distance);
+    let h0 = dec!(0.7275) * parallax - dec!(0.5667);
+
+    let events = rise_transit_set(jd0, date.year(), positions, h0, latitude, longitude)?;
+
+    // The corrections move an event out of the day when it does not happen on that day
+    let within_day = |jd: &JulianDay| jd.day >= jd0.day && jd.day < jd0.day + Decimal::ONE;
+
+    Some((
+        Some(events.rise).filter(within_day),
+        Some(events.transit).filter(within_day),
+        Some(events.set).filter(within_day),
+    ))
+}
+
+/// Returns the instants of ``(rise, transit, set)`` in Universal Time on the given date of an object at a fixed position,
+/// such as a star, for an observer at ``latitude`` (positive north) and ``longitude`` (positive east), all in degrees.
+/// 
+/// Taken from "Rising, Transit, and Setting"
+/// 
+/// ``ra`` and ``dec`` are the apparent right ascension and declination of the object.
+/// For a planet, whose position changes over the day, they should be taken for 0h TD on the date.
+/// 
+/// Returns ``None`` if the object is circumpolar or never rises.
+/// 
+/// **NOTE:** Only the date of ``date`` is used, the fractional part of the day is ignored.
+/// All events fall within the Universal Time day, so the set may precede the rise.
+pub fn object_rise_transit_set(ra: Decimal, dec: Decimal, date: CalendarDate, latitude: Decimal, longitude: Decimal) -> Option<(JulianDay, JulianDay, JulianDay)> {
+    let jd0 = date.julian_day_at_midnight();
+
+    let events = rise_transit_set(jd0, date.year(), [(ra, dec); 3], STAR_STANDARD_ALTITUDE, latitude, longitude)?;
+    Some((events.rise, events.transit, events.set))
+}
+
+/// The instants at which a body rises, transits and sets, in Universal Time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RiseTransitSet {
+    pub rise: JulianDay,
+    pub transit: JulianDay,
+    pub set: JulianDay,
+}
+
+/// Computes the times of rising, transit and setting of a body on the day starting at ``jd0`` (0h UT).
+/// 
+/// ``positions`` holds the apparent ``(right ascension, declination)`` of the body in degrees
+/// at 0h TD on the previous day, the day itself and the next day. ``h0`` is the standard altitude of the body.
+/// 
+/// Returns ``None`` if the body is circumpolar or never rises.
+pub(crate) fn rise_transit_set(
+    jd0: JulianDay,
+    year: i32,
+    positions: [(Decimal, Decimal); 3],
+    h0: Decimal,
+    latitude: Decimal,
+    longitude: Decimal,
+) -> Option<RiseTransitSet> {
+    let theta0 = greenwich_mean_sidereal_time(jd0);
+    let dt = delta_t(year);
+
+    let (alpha2, delta2) = positions[1];
+    let cos_h0 = (sin_deg(h0) - sin_deg(latitude) * sin_deg(delta2)) / (cos_deg(latitude) * cos_deg(delta2));
+    if cos_h0.abs() > Decimal::ONE {
+        return None;
+    }
+    let big_h0 = acos_deg(cos_h0);
+
+    let m0 = normalize_fraction((alpha2 - longitude - theta0) / dec!(360));
+    let mut m1 = normalize_fraction(m0 - big_h0 / dec!(360));
+    let mut m2 = normalize_fraction(m0 + big_h0 / dec!(360));
+    let mut transit = m0;
+
+    let alphas = unwrap_right_ascensions([positions[0].0, positions[1].0, positions[2].0]);
+    let deltas = [positions[0].1, positions[1].1, positions[2].1];
+
+    for _ in 0..3 {
+        let local_hour_angle = |m: Decimal| {
+            let theta = theta0 + dec!(360.985647) * m;
+            let n = m + dt / dec!(86400);
+            let alpha = interpolate3(alphas[0], alphas[1], alphas[2], n);
+            let delta = interpolate3(deltas[0], deltas[1], deltas[2], n);
+            (normalize_degrees(theta + longitude - alpha + dec!(180)) - dec!(180), delta)
+        };
+
+        let (h, _) = local_hour_angle(transit);
+        transit -= h / dec!(360);
+
+        for m in [&mut m1, &mut m2] {
+            let (h, delta) = local_hour_angle(*m);
+            let altitude = asin_deg(sin_deg(latitude) * sin_deg(delta) + cos_deg(latitude) * cos_deg(delta) * cos_deg(h));
+            *m += (altitude - h0) / (dec!(360) * cos_deg(delta) * cos_deg(latitude) * sin_deg(h));
+        }
+    }
+
+    Some(RiseTransitSet {
+        rise: jd0 + m1,
+        transit: jd0 + transit,
+        set: jd0 + m2,
+    })
+}
+
+/// Reduces a fraction of a day to the range ``[0, 1)``.
+fn normalize_fraction(m: Decimal) -> Decimal {
+    m - m.floor()
+}
+
+/// Removes the jump at 360 degrees between consecutive right ascensions, so that they can be interpolated.
+fn unwrap_right_ascensions(alphas: [Decimal; 3]) -> [Decimal; 3] {
+    let mut out = alphas;
+    for i in 1..3 {
+        while out[i] - out[i - 1] > dec!(180) {
+            out[i] -= dec!(360);
+        }
+        while out[i] - out[i - 1] < dec!(-180) {
+            out[i] += dec!(360);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rise_set::*;
+
+    fn hours_minutes(jd: JulianDay) -> (u8, u8) {
+        let (h, m, _) = CalendarDate::try_from(jd).unwrap().time_of_day();
+        (h, m)
+    }
+
+    #[test]
+    fn test_sunrise_sunset() {
+        // London, 2024 June 21st: sunrise at 3h43m UT and sunset at 20h21m UT
+        let date = CalendarDate::new(2024, 6, dec!(21));
+        let (rise, set) = sunrise_sunset(date, dec!(51.5074), dec!(-0.1278)).unwrap();
+
+        let (h, m) = hours_minutes(rise);
+        assert!(h == 3 && (42..=44).contains(&m), "sunrise at {}h{}m", h, m);
+
+        let (h, m) = hours_minutes(set);
+        assert!(h == 20 && (20..=22).contains(&m), "sunset at {}h{}m", h, m);
+
+        assert!(rise.day > JulianDay::from(date).day);
+        assert!(set.day < JulianDay::from(date).day + Decimal::ONE);
+    }
+
+    #[test]
+    fn test_sunrise_sunset_polar() {
+        // Tromsø has midnight sun in June and polar night in December
+        assert_eq!(sunrise_sunset(CalendarDate::new(2024, 6, dec!(21)), dec!(69.6496), dec!(18.9560)), None);
+        assert_eq!(sunrise_sunset(CalendarDate::new(2024, 12, dec!(21)), dec!(69.6496), dec!(18.9560)), None);
+
+        assert!(sunrise_sunset(CalendarDate::new(2024, 3, dec!(20)), dec!(69.6496), dec!(18.9560)).is_some());
+    }
+
+    #[test]
+    fn test_object_rise_transit_set() {
+        // Example 15.a, Venus at Boston on 1988 March 20th: rise at 12h25m, transit at 19h41m and set at 2h55m UT.
+        // Keeping Venus at its position at 0h TD shifts the events by a couple of minutes.
+        let date = CalendarDate::new(1988, 3, dec!(20));
+        let (rise, transit, set) = object_rise_transit_set(dec!(41.73129), dec!(18.44092), date, dec!(42.3333), dec!(-71.0833)).unwrap();
+
+        let (h, m) = hours_minutes(rise);
+        assert!(h == 12 && (21..=29).contains(&m), "rise at {}h{}m", h, m);
+
+        let (h, m) = hours_minutes(transit);
+        assert!(h == 19 && (37..=45).contains(&m), "transit at {}h{}m", h, m);
+
+        let (h, m) = hours_minutes(set);
+        assert!(h == 2 && (51..=59).contains(&m), "set at {}h{}m", h, m);
+    }
+
+    #[test]
+    fn test_object_rise_transit_set_circumpolar() {
+        // From London, Polaris never sets and a star near the south celestial pole never rises
+        let date = CalendarDate::new(2024, 1, dec!(1));
+        assert_eq!(object_rise_transit_set(dec!(37.95), dec!(89.26), date, dec!(51.5074), dec!(-0.1278)), None);
+        assert_eq!(object_rise_transit_set(dec!(95.99), dec!(-80), date, dec!(51.5074), dec!(-0.1278)), None);
+    }
+
+    #[test]
+    fn test_moonrise_moonset() {
+        // London, 2024 January 2nd: transit at 4h43m UT, moonset at 11h18m UT and moonrise at 23h03m UT
+        let date = CalendarDate::new(2024, 1, dec!(2));
+        let (rise, transit, set) = moonrise_moonset(date, dec!(51.5074), dec!(-0.1278)).unwrap();
+
+        let (h, m) = hours_minutes(rise.unwrap());
+        assert!(h == 23 && (1..=5).contains(&m), "moonrise at {}h{}m", h, m);
+
+        let (h, m) = hours_minutes(set.unwrap());
+        assert!(h == 11 && (16..=20).contains(&m), "moonset at {}h{}m", h, m);
+
+        let (h, m) = hours_minutes(transit.unwrap());
+        assert!(h == 4 && (38..=48).contains(&m), "transit at {}h{}m", h, m);
+    }
+
+    #[test]
+    fn test_moonrise_moonset_missing() {
+        // London, 2024 January 3rd: the Moon rises on the 2nd at 23h03m UT and again on the 4th at 0h12m UT,
+        // but it still transits and sets on the 3rd
+        let (rise, transit, set) = moonrise_moonset(CalendarDate::new(2024, 1, dec!(3)), dec!(51.5074), dec!(-0.1278)).unwrap();
+        assert_eq!(rise, None);
+        assert!(transit.is_some());
+        let (h, m) = hours_minutes(set.unwrap());
+        assert!(h == 11 && (22..=30).contains(&m), "moonset at {}h{}m", h, m);
+
+        let (rise, _, _) = moonrise_moonset(CalendarDate::new(2024, 1, dec!(4)), dec!(51.5074), dec!(-0.1278)).unwrap();
+        assert_eq!(hours_minutes(rise.unwrap()).0, 0);
+    }
+}
@@ -0,0 +1,157 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::cos_deg;
+use crate::julian::JulianDay;
+
+/// Periodic terms ``(A, B, C)`` used to correct the mean instant of an equinox or solstice.
+const PERIODIC_TERMS: [(Decimal, Decimal, Decimal); 24] = [
+    (dec!(485), dec!(324.96), dec!(1934.136)),
+    (dec!(203), dec!(337.23), dec!(32964.467)),
+    (dec!(199), dec!(342.08), dec!(20.186)),
+    (dec!(182), dec!(27.85), dec!(445267.112)),
+    (dec!(156), dec!(73.14), dec!(45036.886)),
+    (dec!(136), dec!(171.52), dec!(22518.443)),
+    (dec!(77), dec!(222.54), dec!(65928.934)),
+    (dec!(74), dec!(296.72), dec!(3034.906)),
+    (dec!(70), dec!(243.58), dec!(9037.513)),
+    (dec!(58), dec!(119.81), dec!(33718.147)),
+    (dec!(52), dec!(297.17), dec!(150.678)),
+    (dec!(50), dec!(21.02), dec!(2281.226)),
+    (dec!(45), dec!(247.54), dec!(29929.562)),
+    (dec!(44), dec!(325.15), dec!(31555.956)),
+    (dec!(29), dec!(60.93), dec!(4443.417)),
+    (dec!(18), dec!(155.12), dec!(67555.328)),
+    (dec!(17), dec!(288.79), dec!(4562.452)),
+    (dec!(16), dec!(198.04), dec!(62894.029)),
+    (dec!(14), dec!(199.76), dec!(31436.921)),
+    (dec!(12), dec!(95.39), dec!(14577.848)),
+    (dec!(12), dec!(287.11), dec!(31931.756)),
+    (dec!(12), dec!(320.81), dec!(34777.259)),
+    (dec!(9), dec!(227.73), dec!(1222.114)),
+    (dec!(8), dec!(15.45), dec!(16859.074)),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Season {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+/// Returns the instant of the March equinox of the given year.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn march_equinox(year: i32) -> JulianDay {
+    season(year, Season::MarchEquinox)
+}
+
+/// Returns the instant of the June solstice of the given year.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn june_solstice(year: i32) -> JulianDay {
+    season(year, Season::JuneSolstice)
+}
+
+/// Returns the instant of the September equinox of the given year.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn september_equinox(year: i32) -> JulianDay {
+    season(year, Season::SeptemberEquinox)
+}
+
+/// Returns the instant of the December solstice of the given year.
+/// 
+/// **NOTE:** The result is expressed in Dynamical Time (TD), not Universal Time.
+pub fn december_solstice(year: i32) -> JulianDay {
+    season(year, Season::DecemberSolstice)
+}
+
+/// Taken from "Equinoxes and Solstices"
+/// 
+/// Valid for the years -1000 to +3000. The mean instant is corrected by the periodic terms,
+/// giving a result accurate to within a minute for the years 1951 to 2050.
+fn season(year: i32, s: Season) -> JulianDay {
+    let jde0 = mean_season(year, s);
+
+    let t = JulianDay::new(jde0).centuries_since_j2000();
+    let w = dec!(35999.373) * t - dec!(2.47);
+    let delta_lambda = Decimal::ONE + dec!(0.0334) * cos_deg(w) + dec!(0.0007) * cos_deg(dec!(2) * w);
+
+    let s: Decimal = PERIODIC_TERMS.iter()
+        .map(|&(a, b, c)| a * cos_deg(b + c * t))
+        .sum();
+
+    JulianDay::new(jde0 + (dec!(0.00001) * s) / delta_lambda)
+}
+
+/// Returns the mean instant (JDE0) of the equinox or solstice.
+fn mean_season(year: i32, s: Season) -> Decimal {
+    let (y, coefficients) = if year < 1000 {
+        let y = Decimal::from(year) / dec!(1000);
+        let coefficients = match s {
+            Season::MarchEquinox => [dec!(1721139.29189), dec!(365242.13740), dec!(0.06134), dec!(0.00111), dec!(-0.00071)],
+            Season::JuneSolstice => [dec!(1721233.25401), dec!(365241.72562), dec!(-0.05323), dec!(0.00907), dec!(0.00025)],
+            Season::SeptemberEquinox => [dec!(1721325.70455), dec!(365242.49558), dec!(-0.11677), dec!(-0.00297), dec!(0.00074)],
+            Season::DecemberSolstice => [dec!(1721414.39987), dec!(365242.88257), dec!(-0.00769), dec!(-0.00933), dec!(-0.00006)],
+        };
+        (y, coefficients)
+    } else {
+        let y = Decimal::from(year - 2000) / dec!(1000);
+        let coefficients = match s {
+            Season::MarchEquinox => [dec!(2451623.80984), dec!(365242.37404), dec!(0.05169), dec!(-0.00411), dec!(-0.00057)],
+            Season::JuneSolstice => [dec!(2451716.56767), dec!(365241.62603), dec!(0.00325), dec!(0.00888), dec!(-0.00030)],
+            Season::SeptemberEquinox => [dec!(2451810.21715), dec!(365242.01767), dec!(-0.11575), dec!(0.00337), dec!(0.00078)],
+            Season::DecemberSolstice => [dec!(2451900.05952), dec!(365242.74049), dec!(-0.06223), dec!(-0.00823), dec!(0.00032)],
+        };
+        (y, coefficients)
+    };
+
+    coefficients.iter().rev().fold(Decimal::ZERO, |acc, &c| acc * y + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::julian::CalendarDate;
+    use crate::seasons::*;
+
+    #[test]
+    fn test_june_solstice() {
+        // Example 27.a
+        let jde = june_solstice(1962);
+        assert_eq!(jde.round_to(5), JulianDay::new(dec!(2437837.39245)));
+
+        // 1962 June 21 at 21h25m TD
+        let date = CalendarDate::try_from(jde).unwrap();
+        assert_eq!((date.year(), date.month(), date.day().trunc()), (1962, 6, dec!(21)));
+
+        let (h, m, _) = date.time_of_day();
+        assert_eq!((h, m), (21, 25));
+    }
+
+    #[test]
+    fn test_seasons_order() {
+        for year in [-500, 0, 999, 1000, 1582, 2000, 2024, 2999] {
+            let march = march_equinox(year);
+            let june = june_solstice(year);
+            let september = september_equinox(year);
+            let december = december_solstice(year);
+
+            assert!(march.day < june.day);
+            assert!(june.day < september.day);
+            assert!(september.day < december.day);
+            assert!(december.day - march.day < dec!(366));
+        }
+    }
+
+    #[test]
+    fn test_march_equinox() {
+        // 2000 March 20 at 7h35m UT, or 7h36m TD
+        let date = CalendarDate::try_from(march_equinox(2000)).unwrap();
+        assert_eq!((date.month(), date.day().trunc()), (3, dec!(20)));
+
+        let (h, m, _) = date.time_of_day();
+        assert_eq!((h, m), (7, 36));
+    }
+}
@@ -0,0 +1,47 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{degrees_to_hms, normalize_degrees};
+use crate::julian::JulianDay;
+
+/// Returns the mean sidereal time at Greenwich for any instant, in degrees between 0 and 360.
+/// 
+/// Taken from "Sidereal Time at Greenwich"
+/// 
+/// **NOTE:** ``jd`` must be expressed in Universal Time (UT), not Dynamical Time.
+pub fn greenwich_mean_sidereal_time(jd: JulianDay) -> Decimal {
+    let t = jd.centuries_since_j2000();
+
+    let theta = dec!(280.46061837)
+        + dec!(360.98564736629) * (jd - JulianDay::J2000)
+        + dec!(0.000387933) * t * t
+        - t * t * t / dec!(38710000);
+
+    normalize_degrees(theta)
+}
+
+/// Returns the mean sidereal time at Greenwich as ``(hours, minutes, seconds)``.
+pub fn greenwich_mean_sidereal_time_hms(jd: JulianDay) -> (u8, u8, Decimal) {
+    let hms = degrees_to_hms(greenwich_mean_sidereal_time(jd));
+    (u8::try_from(hms.whole).unwrap(), hms.minutes, hms.seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::julian::CalendarDate;
+    use crate::sidereal::*;
+
+    #[test]
+    fn test_greenwich_mean_sidereal_time() {
+        // Example 12.a, 1987 April 10 at 0h UT
+        let jd = JulianDay::from(CalendarDate::new(1987, 4, dec!(10)));
+        assert_eq!(greenwich_mean_sidereal_time(jd).round_dp(6), dec!(197.693195));
+
+        let (h, m, s) = greenwich_mean_sidereal_time_hms(jd);
+        assert_eq!((h, m, s.round_dp(4)), (13, 10, dec!(46.3668)));
+
+        // Example 12.b, 1987 April 10 at 19h21m00s UT
+        let jd = JulianDay::from(CalendarDate::with_time(1987, 4, 10, 19, 21, dec!(0)));
+        assert_eq!(greenwich_mean_sidereal_time(jd).round_dp(6), dec!(128.737873));
+    }
+}
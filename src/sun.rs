@@ -0,0 +1,132 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+
+use crate::angle::{asin_deg, atan2_deg, cos_deg, normalize_degrees, sin_deg, to_degrees};
+use crate::julian::JulianDay;
+use crate::nutation::mean_obliquity;
+
+/// Returns the geometric longitude of the Sun in degrees between 0 and 360, referred to the mean equinox of the date.
+/// 
+/// Taken from "Solar Coordinates", using the low accuracy series which is accurate to about 0.01 degree.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn sun_geometric_longitude(jd: JulianDay) -> Decimal {
+    let t = jd.centuries_since_j2000();
+    normalize_degrees(mean_longitude(t) + equation_of_center(t))
+}
+
+/// Returns the apparent longitude of the Sun in degrees between 0 and 360, corrected for nutation and aberration.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn sun_apparent_longitude(jd: JulianDay) -> Decimal {
+    let t = jd.centuries_since_j2000();
+    let omega = dec!(125.04) - dec!(1934.136) * t;
+
+    normalize_degrees(sun_geometric_longitude(jd) - dec!(0.00569) - dec!(0.00478) * sin_deg(omega))
+}
+
+/// Returns the apparent ``(right ascension, declination)`` of the Sun in degrees.\
+/// The right ascension lies between 0 and 360, the declination between -90 and 90.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn sun_apparent_equatorial(jd: JulianDay) -> (Decimal, Decimal) {
+    let t = jd.centuries_since_j2000();
+    let omega = dec!(125.04) - dec!(1934.136) * t;
+
+    // Mean obliquity of the ecliptic, corrected for the apparent position
+    let epsilon = mean_obliquity(jd) + dec!(0.00256) * cos_deg(omega);
+
+    let lambda = sun_apparent_longitude(jd);
+    let ra = normalize_degrees(atan2_deg(cos_deg(epsilon) * sin_deg(lambda), cos_deg(lambda)));
+    let dec = asin_deg(sin_deg(epsilon) * sin_deg(lambda));
+
+    (ra, dec)
+}
+
+/// Returns the equation of time in minutes, i.e. the apparent solar time minus the mean solar time.\
+/// A positive value means a sundial is ahead of the clock.
+/// 
+/// Taken from "Equation of Time", using the formula by W.M. Smart which is accurate to a few seconds.
+/// 
+/// **NOTE:** ``jd`` should be expressed in Dynamical Time (TD).
+pub fn equation_of_time(jd: JulianDay) -> Decimal {
+    let t = jd.centuries_since_j2000();
+
+    let l0 = mean_longitude(t);
+    let m = mean_anomaly(t);
+    let e = dec!(0.016708634) - dec!(0.000042037) * t - dec!(0.0000001267) * t * t;
+
+    // y = tan²(ε/2)
+    let epsilon = mean_obliquity(jd);
+    let y = (Decimal::ONE - cos_deg(epsilon)) / (Decimal::ONE + cos_deg(epsilon));
+
+    let radians = y * sin_deg(dec!(2) * l0)
+        - dec!(2) * e * sin_deg(m)
+        + dec!(4) * e * y * sin_deg(m) * cos_deg(dec!(2) * l0)
+        - y * y * sin_deg(dec!(4) * l0) / dec!(2)
+        - dec!(1.25) * e * e * sin_deg(dec!(2) * m);
+
+    // The Earth rotates one degree every 4 minutes
+    to_degrees(radians) * dec!(4)
+}
+
+/// Returns the mean longitude of the Sun in degrees, for ``t`` Julian centuries since J2000.0.
+fn mean_longitude(t: Decimal) -> Decimal {
+    dec!(280.46646) + dec!(36000.76983) * t + dec!(0.0003032) * t * t
+}
+
+/// Returns the mean anomaly of the Sun in degrees, for ``t`` Julian centuries since J2000.0.
+fn mean_anomaly(t: Decimal) -> Decimal {
+    dec!(357.52911) + dec!(35999.05029) * t - dec!(0.0001537) * t * t
+}
+
+/// Returns the Sun's equation of the center in degrees, for ``t`` Julian centuries since J2000.0.
+fn equation_of_center(t: Decimal) -> Decimal {
+    let m = mean_anomaly(t);
+
+    (dec!(1.914602) - dec!(0.004817) * t - dec!(0.000014) * t * t) * sin_deg(m)
+        + (dec!(0.019993) - dec!(0.000101) * t) * sin_deg(dec!(2) * m)
+        + dec!(0.000289) * sin_deg(dec!(3) * m)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sun::*;
+
+    #[test]
+    fn test_sun_longitude() {
+        // Example 25.a, 1992 October 13.0 TD
+        let jd = JulianDay::new(dec!(2448908.5));
+        assert!((sun_geometric_longitude(jd) - dec!(199.90988)).abs() < dec!(0.00002));
+        assert!((sun_apparent_longitude(jd) - dec!(199.90895)).abs() < dec!(0.00002));
+    }
+
+    #[test]
+    fn test_sun_apparent_equatorial() {
+        // Example 25.a, 1992 October 13.0 TD
+        let (ra, dec) = sun_apparent_equatorial(JulianDay::new(dec!(2448908.5)));
+        assert!((ra - dec!(198.38083)).abs() < dec!(0.0001));
+        assert!((dec - dec!(-7.78507)).abs() < dec!(0.0001));
+    }
+
+    #[test]
+    fn test_equation_of_time() {
+        // Example 28.b, 1992 October 13.0 TD, E = 13m42.7s
+        let e = equation_of_time(JulianDay::new(dec!(2448908.5)));
+        assert!((e - dec!(13.712)).abs() < dec!(0.005));
+
+        // The sundial runs behind the clock in mid-February
+        assert!(equation_of_time(JulianDay::from(crate::julian::CalendarDate::new(2000, 2, dec!(12)))) < dec!(-14));
+    }
+
+    #[test]
+    fn test_sun_longitude_at_seasons() {
+        // The apparent longitude is a multiple of 90 degrees at the equinoxes and solstices
+        let march = crate::seasons::march_equinox(2000);
+        let june = crate::seasons::june_solstice(2000);
+
+        let lambda = sun_apparent_longitude(march);
+        assert!(lambda < dec!(0.01) || lambda > dec!(359.99));
+        assert!((sun_apparent_longitude(june) - dec!(90)).abs() < dec!(0.01));
+    }
+}
@@ -0,0 +1,82 @@
+use rust_decimal::Decimal;
+use rust_decimal::dec;
+use core::convert::TryFrom;
+use time::{Date, Month};
+
+use crate::julian::{Calendar, CalendarDate, CalendarDateError, JulianDay, julian_calendar_date};
+
+impl From<Date> for CalendarDate {
+    /// Converts a time::Date into a CalendarDate.
+    /// 
+    /// **NOTE:** time::Date uses the proleptic Gregorian calendar, while CalendarDate switches to the Julian calendar
+    /// before 1582 October 15th. Earlier dates are converted accordingly, e.g. ``1582-10-14`` becomes ``1582-10-04``.\
+    /// time::Date supports the years -9999 to 9999, all of which are converted, including those before JulianDay 0 (-4712 January 1st, Julian).
+    fn from(date: Date) -> Self {
+        let cd = CalendarDate::new(date.year(), u8::from(date.month()), Decimal::from(date.day()));
+        if cd.get_calendar() == Calendar::Gregorian {
+            return cd;
+        }
+
+        // time::Date counts Julian Day Numbers, which begin at noon
+        let jd = JulianDay::new(Decimal::from(date.to_julian_day()) - dec!(0.5));
+        julian_calendar_date(jd)
+    }
+}
+
+impl TryFrom<CalendarDate> for Date {
+    type Error = CalendarDateError;
+
+    /// Converts a CalendarDate into a time::Date, discarding the fractional part of the day.
+    /// 
+    /// Fails for dates before 1582 October 15th, which fall under the Julian calendar,
+    /// and for dates outside of the years -9999 to 9999 supported by time::Date.
+    fn try_from(cd: CalendarDate) -> Result<Self, Self::Error> {
+        if cd.get_calendar() == Calendar::Julian {
+            return Err(CalendarDateError::UnrepresentableDate);
+        }
+
+        let month = Month::try_from(cd.month()).map_err(|_| CalendarDateError::UnrepresentableDate)?;
+        let day = cd.day().trunc().try_into().map_err(|_| CalendarDateError::UnrepresentableDate)?;
+
+        Date::from_calendar_date(cd.year(), month, day).map_err(|_| CalendarDateError::UnrepresentableDate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time_compat::*;
+
+    #[test]
+    fn test_from_date() {
+        let date = Date::from_calendar_date(1957, Month::October, 4).unwrap();
+        assert_eq!(CalendarDate::from(date), CalendarDate::new(1957, 10, dec!(4)));
+
+        // Proleptic Gregorian dates before the reform are converted into the Julian calendar
+        let date = Date::from_calendar_date(1582, Month::October, 14).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (1582, 10, dec!(4)));
+
+        let date = Date::from_calendar_date(-123, Month::December, 28).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-123, 12, dec!(31)));
+
+        // Dates before JulianDay 0 are shifted by whole cycles of the Julian calendar
+        let date = Date::from_calendar_date(-4713, Month::November, 23).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-4713, 12, dec!(31)));
+
+        let date = Date::from_calendar_date(-9999, Month::January, 1).unwrap();
+        let cd = CalendarDate::from(date);
+        assert_eq!((cd.year(), cd.month(), cd.day()), (-9999, 3, dec!(19)));
+    }
+
+    #[test]
+    fn test_try_into_date() {
+        let cd = CalendarDate::new(1957, 10, dec!(4.81));
+        assert_eq!(Date::try_from(cd).unwrap(), Date::from_calendar_date(1957, Month::October, 4).unwrap());
+
+        assert!(Date::try_from(CalendarDate::new(1582, 10, dec!(4))).is_err());
+        assert!(Date::try_from(CalendarDate::new(2001, 2, dec!(29))).is_err());
+        assert!(Date::try_from(CalendarDate::new(10000, 1, dec!(1))).is_err());
+    }
+}